@@ -0,0 +1,334 @@
+use crate::fields::{self, FieldError};
+use crate::BlockHeader;
+use ethereum_types::{Bloom, H160, H256, H64, U256};
+use rlp::{Decodable, DecoderError, Encodable, Rlp, RlpStream};
+use tiny_keccak::{Hasher, Keccak};
+
+/// A strongly typed execution-layer block header covering every fork up to Pectra.
+///
+/// Unlike [`BlockHeader`], which mirrors the database row and stores its values as strings, every field
+/// here holds its native type, so it only has to be parsed once. Fields introduced by a fork are
+/// optional and are `None` for headers that predate it; the RLP encoding appends them in fork order,
+/// so a single type encodes and decodes the header of every era.
+///
+/// # Fields
+///
+/// - `parent_hash`: The hash of the parent block.
+/// - `ommers_hash`: The hash of the RLP-encoded list of ommer (uncle) headers.
+/// - `beneficiary`: The address receiving the block's priority fees (and, before the Merge, the reward).
+/// - `state_root`, `transactions_root`, `receipts_root`: The roots of the state, transaction and receipt tries.
+/// - `logs_bloom`: The bloom filter of every log emitted in the block.
+/// - `difficulty`: The proof-of-work difficulty; zero after the Merge.
+/// - `number`, `gas_limit`, `gas_used`, `timestamp`: The block's number, gas accounting and timestamp.
+/// - `extra_data`: Arbitrary data set by the block producer.
+/// - `mix_hash`: The proof-of-work mix hash, or the beacon chain randomness after the Merge.
+/// - `nonce`: The proof-of-work nonce; zero after the Merge.
+/// - `base_fee_per_gas`: The EIP-1559 base fee, from London.
+/// - `withdrawals_root`: The root of the withdrawals trie, from Shanghai.
+/// - `blob_gas_used`, `excess_blob_gas`: The EIP-4844 blob gas accounting, from Cancun.
+/// - `parent_beacon_block_root`: The EIP-4788 parent beacon block root, from Cancun.
+/// - `requests_hash`: The EIP-7685 commitment to execution layer requests, from Prague.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExecutionHeader {
+    pub parent_hash: H256,
+    pub ommers_hash: H256,
+    pub beneficiary: H160,
+    pub state_root: H256,
+    pub transactions_root: H256,
+    pub receipts_root: H256,
+    pub logs_bloom: Bloom,
+    pub difficulty: U256,
+    pub number: u64,
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    pub timestamp: u64,
+    pub extra_data: Vec<u8>,
+    pub mix_hash: H256,
+    pub nonce: H64,
+    pub base_fee_per_gas: Option<u64>,
+    pub withdrawals_root: Option<H256>,
+    pub blob_gas_used: Option<u64>,
+    pub excess_blob_gas: Option<u64>,
+    pub parent_beacon_block_root: Option<H256>,
+    pub requests_hash: Option<H256>,
+}
+
+impl ExecutionHeader {
+    /// The number of fields every header carries, before any fork-specific field.
+    const BASE_FIELDS: usize = 15;
+
+    /// Computes the Keccak256 hash of the RLP-encoded header, i.e. its block hash.
+    pub fn hash(&self) -> H256 {
+        let mut hasher = Keccak::v256();
+        let mut output = [0u8; 32];
+        hasher.update(&rlp::encode(self));
+        hasher.finalize(&mut output);
+        H256::from_slice(&output)
+    }
+
+    /// Returns the number of fork-specific fields that are encoded after the base fields.
+    ///
+    /// This is the position of the last field that is set, so a missing field followed by a set one is
+    /// encoded as zero rather than shifting the later fields into its slot.
+    fn optional_fields(&self) -> usize {
+        [
+            self.base_fee_per_gas.is_some(),
+            self.withdrawals_root.is_some(),
+            self.blob_gas_used.is_some(),
+            self.excess_blob_gas.is_some(),
+            self.parent_beacon_block_root.is_some(),
+            self.requests_hash.is_some(),
+        ]
+        .iter()
+        .rposition(|&present| present)
+        .map_or(0, |last| last + 1)
+    }
+}
+
+impl Encodable for ExecutionHeader {
+    fn rlp_append(&self, stream: &mut RlpStream) {
+        let optional_fields = self.optional_fields();
+        stream.begin_list(Self::BASE_FIELDS + optional_fields);
+        stream.append(&self.parent_hash);
+        stream.append(&self.ommers_hash);
+        stream.append(&self.beneficiary);
+        stream.append(&self.state_root);
+        stream.append(&self.transactions_root);
+        stream.append(&self.receipts_root);
+        stream.append(&self.logs_bloom);
+        stream.append(&self.difficulty);
+        stream.append(&self.number);
+        stream.append(&self.gas_limit);
+        stream.append(&self.gas_used);
+        stream.append(&self.timestamp);
+        stream.append(&self.extra_data);
+        stream.append(&self.mix_hash);
+        stream.append(&self.nonce);
+
+        if optional_fields > 0 {
+            stream.append(&self.base_fee_per_gas.unwrap_or_default());
+        }
+        if optional_fields > 1 {
+            stream.append(&self.withdrawals_root.unwrap_or_default());
+        }
+        if optional_fields > 2 {
+            stream.append(&self.blob_gas_used.unwrap_or_default());
+        }
+        if optional_fields > 3 {
+            stream.append(&self.excess_blob_gas.unwrap_or_default());
+        }
+        if optional_fields > 4 {
+            stream.append(&self.parent_beacon_block_root.unwrap_or_default());
+        }
+        if optional_fields > 5 {
+            stream.append(&self.requests_hash.unwrap_or_default());
+        }
+    }
+}
+
+impl Decodable for ExecutionHeader {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        let item_count = rlp.item_count()?;
+        if !(Self::BASE_FIELDS..=Self::BASE_FIELDS + 6).contains(&item_count) {
+            return Err(DecoderError::RlpIncorrectListLen);
+        }
+        let optional = |index: usize| item_count > Self::BASE_FIELDS + index;
+
+        Ok(ExecutionHeader {
+            parent_hash: rlp.val_at(0)?,
+            ommers_hash: rlp.val_at(1)?,
+            beneficiary: rlp.val_at(2)?,
+            state_root: rlp.val_at(3)?,
+            transactions_root: rlp.val_at(4)?,
+            receipts_root: rlp.val_at(5)?,
+            logs_bloom: rlp.val_at(6)?,
+            difficulty: rlp.val_at(7)?,
+            number: rlp.val_at(8)?,
+            gas_limit: rlp.val_at(9)?,
+            gas_used: rlp.val_at(10)?,
+            timestamp: rlp.val_at(11)?,
+            extra_data: rlp.val_at(12)?,
+            mix_hash: rlp.val_at(13)?,
+            nonce: rlp.val_at(14)?,
+            base_fee_per_gas: optional(0).then(|| rlp.val_at(15)).transpose()?,
+            withdrawals_root: optional(1).then(|| rlp.val_at(16)).transpose()?,
+            blob_gas_used: optional(2).then(|| rlp.val_at(17)).transpose()?,
+            excess_blob_gas: optional(3).then(|| rlp.val_at(18)).transpose()?,
+            parent_beacon_block_root: optional(4).then(|| rlp.val_at(19)).transpose()?,
+            requests_hash: optional(5).then(|| rlp.val_at(20)).transpose()?,
+        })
+    }
+}
+
+impl TryFrom<&BlockHeader> for ExecutionHeader {
+    type Error = FieldError;
+
+    /// Parses a database block header into an `ExecutionHeader`.
+    ///
+    /// The ommers hash is read from `sha3_uncles`, falling back to `ommers_hash`. Fork-specific columns
+    /// that are missing or empty become `None`; every other missing or malformed column is a
+    /// `FieldError` naming that column. The columns are parsed with the same [`fields`] parsers as the
+    /// per-era header structs.
+    fn try_from(header: &BlockHeader) -> Result<Self, FieldError> {
+        let ommers_hash = header.sha3_uncles.clone().or(header.ommers_hash.clone());
+
+        Ok(ExecutionHeader {
+            parent_hash: fields::h256("parent_hash", &header.parent_hash)?,
+            ommers_hash: fields::h256("sha3_uncles", &ommers_hash)?,
+            beneficiary: fields::h160("miner", &header.miner)?,
+            state_root: fields::h256("state_root", &header.state_root)?,
+            transactions_root: fields::h256("transaction_root", &header.transaction_root)?,
+            receipts_root: fields::h256("receipts_root", &header.receipts_root)?,
+            logs_bloom: Bloom(fields::fixed_bytes(
+                "logs_bloom",
+                header.logs_bloom.as_deref().unwrap_or_default(),
+            )?),
+            difficulty: fields::u256_or_zero("difficulty", &header.difficulty)?,
+            number: fields::integer("number", header.number)?,
+            gas_limit: fields::integer("gas_limit", header.gas_limit)?,
+            gas_used: fields::integer("gas_used", header.gas_used)?,
+            timestamp: fields::u64_quantity("timestamp", &header.timestamp)?,
+            extra_data: fields::bytes("extra_data", &header.extra_data)?,
+            mix_hash: fields::h256("mix_hash", &header.mix_hash)?,
+            nonce: H64(fields::fixed_bytes("nonce", &header.nonce)?),
+            base_fee_per_gas: fields::optional_u64_quantity(
+                "base_fee_per_gas",
+                &header.base_fee_per_gas,
+            )?,
+            withdrawals_root: fields::optional_h256("withdrawals_root", &header.withdrawals_root)?,
+            blob_gas_used: fields::optional_u64_quantity("blob_gas_used", &header.blob_gas_used)?,
+            excess_blob_gas: fields::optional_u64_quantity(
+                "excess_blob_gas",
+                &header.excess_blob_gas,
+            )?,
+            parent_beacon_block_root: fields::optional_h256(
+                "parent_beacon_block_root",
+                &header.parent_beacon_block_root,
+            )?,
+            requests_hash: fields::optional_h256("request_hash", &header.request_hash)?,
+        })
+    }
+}
+
+impl TryFrom<&ExecutionHeader> for BlockHeader {
+    type Error = FieldError;
+
+    /// Converts an `ExecutionHeader` back into the database representation.
+    ///
+    /// Hashes and byte strings are written as `0x`-prefixed lowercase hex and quantities as `0x`-prefixed
+    /// hex without leading zeros. The block hash is computed from the header, and the ommers hash is
+    /// written to both `sha3_uncles` and `ommers_hash`. The total difficulty is not part of the header
+    /// and is left unset.
+    ///
+    /// # Returns
+    ///
+    /// The database header, or a `FieldError` if `number`, `gas_limit` or `gas_used` does not fit in
+    /// the signed `bigint` column that stores it.
+    fn try_from(header: &ExecutionHeader) -> Result<Self, FieldError> {
+        Ok(BlockHeader {
+            block_hash: format!("{:?}", header.hash()),
+            number: fields::to_integer("number", header.number)?,
+            gas_limit: fields::to_integer("gas_limit", header.gas_limit)?,
+            gas_used: fields::to_integer("gas_used", header.gas_used)?,
+            nonce: format!("{:?}", header.nonce),
+            transaction_root: Some(format!("{:?}", header.transactions_root)),
+            receipts_root: Some(format!("{:?}", header.receipts_root)),
+            state_root: Some(format!("{:?}", header.state_root)),
+            base_fee_per_gas: header.base_fee_per_gas.map(|fee| format!("{:#x}", fee)),
+            parent_hash: Some(format!("{:?}", header.parent_hash)),
+            ommers_hash: Some(format!("{:?}", header.ommers_hash)),
+            miner: Some(format!("{:?}", header.beneficiary)),
+            logs_bloom: Some(format!("{:?}", header.logs_bloom)),
+            difficulty: Some(format!("{:#x}", header.difficulty)),
+            totaldifficulty: None,
+            sha3_uncles: Some(format!("{:?}", header.ommers_hash)),
+            timestamp: Some(format!("{:#x}", header.timestamp)),
+            extra_data: Some(format!("0x{}", hex::encode(&header.extra_data))),
+            mix_hash: Some(format!("{:?}", header.mix_hash)),
+            withdrawals_root: header.withdrawals_root.map(|root| format!("{:?}", root)),
+            blob_gas_used: header.blob_gas_used.map(|gas| format!("{:#x}", gas)),
+            excess_blob_gas: header.excess_blob_gas.map(|gas| format!("{:#x}", gas)),
+            parent_beacon_block_root: header
+                .parent_beacon_block_root
+                .map(|root| format!("{:?}", root)),
+            request_hash: header.requests_hash.map(|hash| format!("{:?}", hash)),
+        })
+    }
+}
+
+impl TryFrom<ExecutionHeader> for BlockHeader {
+    type Error = FieldError;
+
+    fn try_from(header: ExecutionHeader) -> Result<Self, FieldError> {
+        BlockHeader::try_from(&header)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pectra_header() -> ExecutionHeader {
+        ExecutionHeader {
+            parent_hash: H256::repeat_byte(1),
+            number: 411443,
+            gas_limit: 36_000_000,
+            gas_used: 21_000,
+            timestamp: 1_747_474_548,
+            extra_data: b"reth".to_vec(),
+            base_fee_per_gas: Some(7),
+            withdrawals_root: Some(H256::repeat_byte(2)),
+            blob_gas_used: Some(131_072),
+            excess_blob_gas: Some(0),
+            parent_beacon_block_root: Some(H256::repeat_byte(3)),
+            requests_hash: Some(H256::repeat_byte(4)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_rlp_round_trip_for_every_layout() {
+        let pectra = pectra_header();
+        let legacy = ExecutionHeader {
+            difficulty: U256::from(17_179_869_184u64),
+            nonce: H64::from_low_u64_be(0x42),
+            ..Default::default()
+        };
+
+        for header in [pectra, legacy] {
+            let encoded = rlp::encode(&header);
+            let decoded: ExecutionHeader = rlp::decode(&encoded).unwrap();
+            assert_eq!(decoded, header);
+        }
+        assert_eq!(
+            Rlp::new(&rlp::encode(&pectra_header())).item_count(),
+            Ok(21)
+        );
+    }
+
+    #[test]
+    fn test_block_header_conversion_is_lossless() {
+        let header = pectra_header();
+        let db_header = BlockHeader::try_from(&header).unwrap();
+
+        assert_eq!(db_header.block_hash, format!("{:?}", header.hash()));
+        assert_eq!(db_header.timestamp.as_deref(), Some("0x68285874"));
+        assert_eq!(ExecutionHeader::try_from(&db_header).unwrap(), header);
+
+        let mut corrupt = db_header;
+        corrupt.parent_hash = Some("0x12".to_string());
+        assert_eq!(
+            ExecutionHeader::try_from(&corrupt).unwrap_err().field,
+            "parent_hash"
+        );
+
+        let overflowing = ExecutionHeader {
+            number: u64::MAX,
+            ..header
+        };
+        assert_eq!(
+            BlockHeader::try_from(&overflowing).unwrap_err().field,
+            "number"
+        );
+    }
+}
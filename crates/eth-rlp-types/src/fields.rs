@@ -0,0 +1,198 @@
+use ethereum_types::{H160, H256, U256};
+use std::fmt;
+use std::str::FromStr;
+
+// Column parsers shared by every typed view of a `BlockHeader` row: the `ExecutionHeader` conversion
+// in this crate and the per-era header structs of `eth-rlp-verify`. Keeping a single implementation
+// means the same stored row is accepted or rejected the same way wherever it is parsed.
+
+/// A database column that is missing or cannot be parsed into its header field.
+///
+/// # Fields
+///
+/// - `field`: The name of the offending column, e.g. `parent_hash`.
+/// - `reason`: Why the value was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    pub field: &'static str,
+    pub reason: String,
+}
+
+impl FieldError {
+    /// Builds a `FieldError` for `field` from any displayable reason.
+    pub fn new(field: &'static str, reason: impl fmt::Display) -> Self {
+        FieldError {
+            field,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "malformed field `{}`: {}", self.field, self.reason)
+    }
+}
+
+impl std::error::Error for FieldError {}
+
+/// Returns the value of an optional column, treating an empty string as missing.
+pub fn optional(value: &Option<String>) -> Option<&str> {
+    value.as_deref().filter(|raw| !raw.is_empty())
+}
+
+/// Returns the value of a required column.
+pub fn required<'a>(field: &'static str, value: &'a Option<String>) -> Result<&'a str, FieldError> {
+    value
+        .as_deref()
+        .ok_or_else(|| FieldError::new(field, "missing value"))
+}
+
+/// Parses a required 32-byte hash column.
+pub fn h256(field: &'static str, value: &Option<String>) -> Result<H256, FieldError> {
+    H256::from_str(required(field, value)?).map_err(|e| FieldError::new(field, e))
+}
+
+/// Parses a column that only exists from some fork onwards; a missing or empty value is `None`.
+pub fn optional_h256(
+    field: &'static str,
+    value: &Option<String>,
+) -> Result<Option<H256>, FieldError> {
+    optional(value)
+        .map(|raw| H256::from_str(raw).map_err(|e| FieldError::new(field, e)))
+        .transpose()
+}
+
+/// Parses a required 20-byte address column.
+pub fn h160(field: &'static str, value: &Option<String>) -> Result<H160, FieldError> {
+    H160::from_str(required(field, value)?).map_err(|e| FieldError::new(field, e))
+}
+
+/// Parses a required hexadecimal quantity column.
+pub fn u256(field: &'static str, value: &Option<String>) -> Result<U256, FieldError> {
+    U256::from_str(required(field, value)?).map_err(|e| FieldError::new(field, e))
+}
+
+/// Parses an optional hexadecimal quantity column, treating a missing value as zero.
+///
+/// Only `difficulty` is read this way: proof-of-stake rows may leave it empty.
+pub fn u256_or_zero(field: &'static str, value: &Option<String>) -> Result<U256, FieldError> {
+    match value {
+        Some(_) => u256(field, value),
+        None => Ok(U256::zero()),
+    }
+}
+
+/// Parses a required hexadecimal quantity column that must fit in 64 bits.
+pub fn u64_quantity(field: &'static str, value: &Option<String>) -> Result<u64, FieldError> {
+    fits_u64(field, u256(field, value)?)
+}
+
+/// Parses a 64-bit quantity column that only exists from some fork onwards; a missing or empty value
+/// is `None`.
+pub fn optional_u64_quantity(
+    field: &'static str,
+    value: &Option<String>,
+) -> Result<Option<u64>, FieldError> {
+    optional(value)
+        .map(|raw| {
+            let quantity = U256::from_str(raw).map_err(|e| FieldError::new(field, e))?;
+            fits_u64(field, quantity)
+        })
+        .transpose()
+}
+
+/// Converts a `bigint` column into an unsigned integer, rejecting negative values.
+pub fn integer(field: &'static str, value: i64) -> Result<u64, FieldError> {
+    u64::try_from(value).map_err(|_| FieldError::new(field, format!("negative value {}", value)))
+}
+
+/// Converts an unsigned integer into a `bigint` column, rejecting values above `i64::MAX`.
+pub fn to_integer(field: &'static str, value: u64) -> Result<i64, FieldError> {
+    i64::try_from(value).map_err(|_| {
+        FieldError::new(
+            field,
+            format!("value {} does not fit in a signed 64-bit column", value),
+        )
+    })
+}
+
+/// Decodes a variable-length byte column; a missing value or a bare "0x" is empty.
+pub fn bytes(field: &'static str, value: &Option<String>) -> Result<Vec<u8>, FieldError> {
+    let raw = value.as_deref().unwrap_or_default();
+    hex::decode(raw.strip_prefix("0x").unwrap_or(raw)).map_err(|e| FieldError::new(field, e))
+}
+
+/// Decodes a fixed-size byte column such as `logs_bloom` or `nonce`.
+///
+/// An empty string or a bare "0x" yields an all-zero array and odd-length input is left-padded with a
+/// zero nibble; any other value must decode to exactly `N` bytes.
+pub fn fixed_bytes<const N: usize>(field: &'static str, raw: &str) -> Result<[u8; N], FieldError> {
+    if raw.is_empty() || raw == "0x" {
+        return Ok([0u8; N]);
+    }
+
+    let content = raw.strip_prefix("0x").unwrap_or(raw);
+    let padded = if !content.len().is_multiple_of(2) {
+        format!("0{}", content)
+    } else {
+        content.to_string()
+    };
+    let decoded = hex::decode(&padded)
+        .map_err(|e| FieldError::new(field, format!("Failed to decode hex string: {}", e)))?;
+
+    decoded.try_into().map_err(|decoded: Vec<u8>| {
+        FieldError::new(
+            field,
+            format!(
+                "Invalid input length: expected {}, got {}",
+                N,
+                decoded.len()
+            ),
+        )
+    })
+}
+
+fn fits_u64(field: &'static str, value: U256) -> Result<u64, FieldError> {
+    if value > U256::from(u64::MAX) {
+        return Err(FieldError::new(field, "value does not fit in 64 bits"));
+    }
+    Ok(value.as_u64())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_errors_name_the_column() {
+        assert_eq!(
+            h256("parent_hash", &None),
+            Err(FieldError::new("parent_hash", "missing value"))
+        );
+        assert_eq!(integer("gas_used", -1).unwrap_err().field, "gas_used");
+        assert_eq!(to_integer("number", u64::MAX).unwrap_err().field, "number");
+        assert_eq!(
+            bytes("extra_data", &Some("0xzz".to_string()))
+                .unwrap_err()
+                .field,
+            "extra_data"
+        );
+        assert_eq!(bytes("extra_data", &Some(String::new())), Ok(vec![]));
+        assert_eq!(u256_or_zero("difficulty", &None), Ok(U256::zero()));
+        assert_eq!(
+            optional_h256("withdrawals_root", &Some(String::new())),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_fixed_bytes_requires_the_exact_length() {
+        assert_eq!(fixed_bytes::<2>("nonce", "0x102"), Ok([0x01, 0x02]));
+        assert_eq!(fixed_bytes::<8>("nonce", "0x"), Ok([0u8; 8]));
+        assert_eq!(
+            fixed_bytes::<8>("nonce", "0x0").unwrap_err(),
+            FieldError::new("nonce", "Invalid input length: expected 8, got 1")
+        );
+    }
+}
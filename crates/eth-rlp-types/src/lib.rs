@@ -1,5 +1,10 @@
+mod execution_header;
+pub mod fields;
+
 use ethereum_types::H256;
+pub use execution_header::ExecutionHeader;
 use eyre::Result;
+pub use fields::FieldError;
 use serde::{Deserialize, Serialize};
 use tiny_keccak::{Hasher, Keccak};
 
@@ -129,38 +134,33 @@ pub trait BlockHeaderTrait {
     /// # Panics
     ///
     /// This function will panic if the length of the decoded bytes does not match the expected size `N`.
+    /// Use [`BlockHeaderTrait::try_hex_to_fixed_array`] to handle malformed input gracefully.
     fn hex_to_fixed_array<const N: usize>(hex_str: &str) -> [u8; N] {
-        if hex_str.is_empty() || hex_str == "0x" {
-            return [0u8; N]; // Return an empty array if the input is empty
-        }
-
-        // Ensure the hex string starts with "0x"
-        let content = hex_str.strip_prefix("0x").unwrap_or(hex_str);
-
-        // Pad with a leading '0' if the length is odd
-        let padded_content = if content.len() % 2 != 0 {
-            format!("0{}", content)
-        } else {
-            content.to_string()
-        };
-
-        // Decode the hex string
-        let bytes = hex::decode(&padded_content).expect("Failed to decode hex string");
-
-        // Check if the decoded length matches the expected size
-        if bytes.len() != N {
-            panic!("Invalid input length: expected {}, got {}", N, bytes.len());
-        }
-
-        // Copy bytes into the fixed-size array
-        let mut array = [0u8; N];
-        array.copy_from_slice(&bytes);
-        array
+        Self::try_hex_to_fixed_array(hex_str).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Converts a hexadecimal string to a fixed-size byte array without panicking.
+    ///
+    /// This is the fallible counterpart of [`BlockHeaderTrait::hex_to_fixed_array`]. An empty string or a
+    /// bare "0x" yields an all-zero array, and odd-length input is left-padded with a zero nibble.
+    ///
+    /// # Arguments
+    ///
+    /// - `hex_str`: A string slice containing a hexadecimal string (e.g., "0x1234...").
+    ///
+    /// # Returns
+    ///
+    /// A `Result` with the decoded array, or an error if the string is not valid hexadecimal or does not
+    /// decode to exactly `N` bytes.
+    fn try_hex_to_fixed_array<const N: usize>(hex_str: &str) -> Result<[u8; N]> {
+        fields::fixed_bytes("hex string", hex_str).map_err(|e| eyre::eyre!(e.reason))
     }
 }
 
+#[cfg(test)]
 struct BlockHeaderImpl;
 
+#[cfg(test)]
 impl BlockHeaderTrait for BlockHeaderImpl {
     fn rlp_encode(&self) -> Vec<u8> {
         vec![]
@@ -200,6 +200,12 @@ mod tests {
         assert_eq!(result, [0u8; 4]);
     }
 
+    #[test]
+    fn test_try_hex_to_fixed_array_rejects_bad_length() {
+        let hex_str = "0x1234";
+        assert!(BlockHeaderImpl::try_hex_to_fixed_array::<8>(hex_str).is_err());
+    }
+
     #[test]
     #[should_panic(expected = "Failed to decode hex string")]
     fn test_invalid_hex_string() {
@@ -0,0 +1,313 @@
+use crate::chain_spec::ChainSpec;
+use crate::eras::EraHeader;
+use crate::error::VerificationError;
+use crate::hash::keccak256;
+use crate::receipts::{Log, Receipt};
+use eth_rlp_types::fields;
+use eth_rlp_types::BlockHeader as VerifiableBlockHeader;
+use ethereum_types::{Bloom, H160, H256};
+use rlp::{Encodable, RlpStream};
+
+/// The number of bytes in a logs bloom.
+pub const BLOOM_BYTES: usize = 256;
+
+/// The 2048-bit bloom filter of the addresses and topics of a set of logs, as stored in the `logs_bloom`
+/// of a receipt or a block header.
+///
+/// Every address and topic sets three bits, chosen from the Keccak256 hash of its bytes. A bloom that
+/// does not have all three bits of an input set cannot cover a log with that input; a bloom that does may
+/// still not, since the bits can have been set by other inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LogsBloom(pub [u8; BLOOM_BYTES]);
+
+impl Default for LogsBloom {
+    fn default() -> Self {
+        LogsBloom([0; BLOOM_BYTES])
+    }
+}
+
+impl LogsBloom {
+    /// Builds the bloom of a list of logs.
+    pub fn from_logs<'a>(logs: impl IntoIterator<Item = &'a Log>) -> Self {
+        let mut bloom = LogsBloom::default();
+        for log in logs {
+            bloom.accrue_log(log);
+        }
+        bloom
+    }
+
+    /// Builds the bloom of a block from the logs of its receipts.
+    ///
+    /// The bloom is recomputed from the logs, so the `logs_bloom` stored in each receipt is not trusted.
+    pub fn from_receipts(receipts: &[Receipt]) -> Self {
+        LogsBloom::from_logs(receipts.iter().flat_map(|receipt| &receipt.logs))
+    }
+
+    /// Sets the three bits of `input` in the bloom.
+    pub fn accrue(&mut self, input: &[u8]) {
+        for (byte, mask) in bloom_bits(input) {
+            self.0[byte] |= mask;
+        }
+    }
+
+    /// Sets the bits of the address and of every topic of a log.
+    pub fn accrue_log(&mut self, log: &Log) {
+        self.accrue(log.address.as_bytes());
+        for topic in &log.topics {
+            self.accrue(topic.as_bytes());
+        }
+    }
+
+    /// Sets every bit that is set in `other`, e.g. to combine the blooms of the receipts of a block.
+    pub fn accrue_bloom(&mut self, other: &LogsBloom) {
+        for (byte, other) in self.0.iter_mut().zip(other.0) {
+            *byte |= other;
+        }
+    }
+
+    /// Returns whether all three bits of `input` are set. `false` means that no log covered by the bloom
+    /// has `input` as its address or one of its topics.
+    pub fn contains(&self, input: &[u8]) -> bool {
+        bloom_bits(input)
+            .into_iter()
+            .all(|(byte, mask)| self.0[byte] & mask == mask)
+    }
+
+    /// Returns whether the bloom may cover a log emitted by `address`.
+    pub fn contains_address(&self, address: &H160) -> bool {
+        self.contains(address.as_bytes())
+    }
+
+    /// Returns whether the bloom may cover a log with `topic` as one of its topics.
+    pub fn contains_topic(&self, topic: &H256) -> bool {
+        self.contains(topic.as_bytes())
+    }
+
+    /// Returns whether every bit that is set in `other` is also set in this bloom.
+    pub fn contains_bloom(&self, other: &LogsBloom) -> bool {
+        self.0
+            .iter()
+            .zip(other.0)
+            .all(|(byte, other)| byte & other == other)
+    }
+}
+
+/// Returns the byte index and bit mask of the three bits an input sets: each of the first three pairs of
+/// bytes of its hash selects one of the 2048 bits, with bit 0 in the last byte of the bloom.
+fn bloom_bits(input: &[u8]) -> [(usize, u8); 3] {
+    let hash = keccak256(input);
+    let hash = hash.as_bytes();
+    [0, 2, 4].map(|i| {
+        let bit = (usize::from(hash[i]) << 8 | usize::from(hash[i + 1])) % (BLOOM_BYTES * 8);
+        (BLOOM_BYTES - 1 - bit / 8, 1 << (bit % 8))
+    })
+}
+
+impl Encodable for LogsBloom {
+    fn rlp_append(&self, stream: &mut RlpStream) {
+        stream.encoder().encode_value(&self.0);
+    }
+}
+
+impl From<Bloom> for LogsBloom {
+    fn from(bloom: Bloom) -> Self {
+        LogsBloom(bloom.0)
+    }
+}
+
+impl From<LogsBloom> for Bloom {
+    fn from(bloom: LogsBloom) -> Self {
+        Bloom(bloom.0)
+    }
+}
+
+/// Parses the `logs_bloom` column of a database block header.
+///
+/// No other field is read and the header is not verified, which keeps the parse cheap enough to run on
+/// every header of a log scan.
+impl TryFrom<&VerifiableBlockHeader> for LogsBloom {
+    type Error = VerificationError;
+
+    fn try_from(header: &VerifiableBlockHeader) -> Result<Self, Self::Error> {
+        let raw = fields::required("logs_bloom", &header.logs_bloom)?;
+        Ok(LogsBloom(fields::fixed_bytes("logs_bloom", raw)?))
+    }
+}
+
+/// Checks whether a block may contain logs matching an address and a set of topics, so that log
+/// scanners can skip blocks without fetching their receipts.
+///
+/// Only the `logs_bloom` of the header is read; the header is expected to have been verified already,
+/// e.g. with [`crate::verify_block`]. A `false` result is definitive. A `true` result may be a false
+/// positive, and the receipts of the block have to be fetched to find the matching logs.
+///
+/// # Arguments
+///
+/// - `block_header`: The verified header of the block.
+/// - `address`: The address that must have emitted the logs, or `None` to match any address.
+/// - `topics`: Topics that must all appear in the block's bloom. Empty to match any topics.
+///
+/// # Returns
+///
+/// Whether the bloom contains the address and every topic, or a `VerificationError::MalformedField` if
+/// the header's `logs_bloom` is missing or malformed.
+pub fn may_contain_logs(
+    block_header: &VerifiableBlockHeader,
+    address: Option<&H160>,
+    topics: &[H256],
+) -> Result<bool, VerificationError> {
+    let bloom = LogsBloom::try_from(block_header)?;
+    Ok(
+        address.is_none_or(|address| bloom.contains_address(address))
+            && topics.iter().all(|topic| bloom.contains_topic(topic)),
+    )
+}
+
+/// Verifies the logs bloom of a block header against the logs of the block's receipts.
+///
+/// The header is verified against its own hash first. The bloom of every receipt must match the logs it
+/// carries, and the bloom recomputed from all logs must equal the `logs_bloom` of the header. Use
+/// [`crate::verify_receipts_root`] to tie the receipts themselves to the header.
+///
+/// # Arguments
+///
+/// - `block_header`: The header of the block.
+/// - `receipts`: The receipts of the block's transactions, in block order.
+/// - `spec`: The `ChainSpec` of the chain the block belongs to.
+///
+/// # Returns
+///
+/// `Ok(())` if the blooms match, `VerificationError::LogsBloomMismatch` naming the offending receipt, or
+/// none for the header, if they do not, or another `VerificationError` if the header is invalid.
+pub fn verify_logs_bloom(
+    block_header: &VerifiableBlockHeader,
+    receipts: &[Receipt],
+    spec: &ChainSpec,
+) -> Result<(), VerificationError> {
+    let block_number = block_header.number as u64;
+    crate::verify_block(
+        block_number,
+        block_header.clone(),
+        &block_header.block_hash,
+        spec,
+    )?;
+    let header = EraHeader::from_header(block_header, &spec.forks)?;
+
+    let mut computed = LogsBloom::default();
+    for (index, receipt) in receipts.iter().enumerate() {
+        let bloom = LogsBloom::from_logs(&receipt.logs);
+        if bloom != receipt.logs_bloom {
+            return Err(VerificationError::LogsBloomMismatch {
+                block_number,
+                receipt: Some(index),
+            });
+        }
+        computed.accrue_bloom(&bloom);
+    }
+
+    if computed != header.logs_bloom() {
+        return Err(VerificationError::LogsBloomMismatch {
+            block_number,
+            receipt: None,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::receipts::ReceiptOutcome;
+    use crate::test_helpers::{
+        create_test_block_header_pectra_hoodi, create_test_block_receipts_pectra_hoodi,
+        create_test_chain,
+    };
+    use std::str::FromStr;
+
+    fn transfer_log() -> Log {
+        Log {
+            address: H160::repeat_byte(0x11),
+            topics: vec![H256::from_str(
+                "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+            )
+            .unwrap()],
+            data: vec![],
+        }
+    }
+
+    #[test]
+    fn test_accrue_matches_ethereum_types() {
+        let log = transfer_log();
+        let mut expected = Bloom::zero();
+        expected.accrue(ethereum_types::BloomInput::Raw(log.address.as_bytes()));
+        expected.accrue(ethereum_types::BloomInput::Raw(log.topics[0].as_bytes()));
+
+        let bloom = LogsBloom::from_logs([&log]);
+        assert_eq!(Bloom::from(bloom), expected);
+        assert_eq!(bloom.0.iter().map(|b| b.count_ones()).sum::<u32>(), 6);
+        assert!(bloom.contains_address(&log.address));
+        assert!(bloom.contains_topic(&log.topics[0]));
+        assert!(!bloom.contains_address(&H160::repeat_byte(0x22)));
+        assert!(LogsBloom::default().contains_bloom(&LogsBloom::default()));
+        assert!(!LogsBloom::default().contains_bloom(&bloom));
+    }
+
+    #[test]
+    fn test_verify_logs_bloom_and_query() {
+        let spec = ChainSpec::hoodi();
+        let log = transfer_log();
+        let receipt = Receipt {
+            tx_type: 2,
+            outcome: ReceiptOutcome::Status(true),
+            cumulative_gas_used: 21_000,
+            logs_bloom: LogsBloom::from_logs([&log]),
+            logs: vec![log.clone()],
+        };
+        let mut header = create_test_chain(1).remove(0);
+        header.logs_bloom = LogsBloom::from_receipts(std::slice::from_ref(&receipt)).into();
+        let block_header = VerifiableBlockHeader::try_from(&header).unwrap();
+
+        assert_eq!(
+            verify_logs_bloom(&block_header, std::slice::from_ref(&receipt), &spec),
+            Ok(())
+        );
+        assert_eq!(
+            verify_logs_bloom(&block_header, &[], &spec),
+            Err(VerificationError::LogsBloomMismatch {
+                block_number: header.number,
+                receipt: None,
+            })
+        );
+        let mut forged = receipt;
+        forged.logs.clear();
+        assert_eq!(
+            verify_logs_bloom(&block_header, &[forged], &spec),
+            Err(VerificationError::LogsBloomMismatch {
+                block_number: header.number,
+                receipt: Some(0),
+            })
+        );
+
+        assert_eq!(
+            may_contain_logs(&block_header, Some(&log.address), &log.topics),
+            Ok(true)
+        );
+        assert_eq!(may_contain_logs(&block_header, None, &[]), Ok(true));
+        assert_eq!(
+            may_contain_logs(&block_header, Some(&H160::repeat_byte(0x22)), &[]),
+            Ok(false)
+        );
+
+        // Hoodi block 411443 emitted no logs, so its real bloom is empty and rules out any address.
+        let hoodi = create_test_block_header_pectra_hoodi();
+        assert_eq!(
+            verify_logs_bloom(&hoodi, &create_test_block_receipts_pectra_hoodi(), &spec),
+            Ok(())
+        );
+        assert_eq!(
+            may_contain_logs(&hoodi, Some(&log.address), &log.topics),
+            Ok(false)
+        );
+        assert_eq!(may_contain_logs(&hoodi, None, &[]), Ok(true));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::constants::*;
+use crate::eras::ForkSchedule;
+use crate::error::VerificationError;
+use ethereum_types::{H256, U256};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{OnceLock, RwLock};
+
+pub const CHAIN_ID_MAINNET: u64 = 1;
+pub const CHAIN_ID_SEPOLIA: u64 = 11155111;
+pub const CHAIN_ID_HOLESKY: u64 = 17000;
+pub const CHAIN_ID_HOODI: u64 = 560048;
+
+/// Describes a chain whose block headers can be verified.
+///
+/// A `ChainSpec` bundles everything the verifiers need to know about a network: its chain id, the hash
+/// of its genesis block, and the `ForkSchedule` that decides which header layout applies to each block.
+/// Built-in specs are provided for mainnet, Sepolia, Holesky and Hoodi; devnets and private chains can
+/// build their own and make them discoverable by chain id with [`register_chain_spec`].
+///
+/// # Fields
+///
+/// - `chain_id`: The EIP-155 chain id of the network.
+/// - `genesis_hash`: The hash of block 0, if known. When set, a header numbered 0 must hash to it.
+/// - `forks`: The activation points of the forks that change the block header layout.
+/// - `terminal_total_difficulty`: The total difficulty at which the chain switched from proof-of-work to
+///   proof-of-stake, if known. Zero means the chain was proof-of-stake from genesis.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainSpec {
+    pub chain_id: u64,
+    pub genesis_hash: Option<H256>,
+    pub forks: ForkSchedule,
+    pub terminal_total_difficulty: Option<U256>,
+}
+
+impl ChainSpec {
+    /// Returns the chain spec of Ethereum mainnet.
+    pub fn mainnet() -> Self {
+        Self::builtin(
+            CHAIN_ID_MAINNET,
+            GENESIS_HASH_MAINNET,
+            ForkSchedule::MAINNET,
+            TERMINAL_TOTAL_DIFFICULTY,
+        )
+    }
+
+    /// Returns the chain spec of the Sepolia testnet.
+    pub fn sepolia() -> Self {
+        Self::builtin(
+            CHAIN_ID_SEPOLIA,
+            GENESIS_HASH_SEPOLIA,
+            ForkSchedule::SEPOLIA,
+            TERMINAL_TOTAL_DIFFICULTY_SEPOLIA,
+        )
+    }
+
+    /// Returns the chain spec of the Holesky testnet.
+    pub fn holesky() -> Self {
+        Self::builtin(
+            CHAIN_ID_HOLESKY,
+            GENESIS_HASH_HOLESKY,
+            ForkSchedule::HOLESKY,
+            0,
+        )
+    }
+
+    /// Returns the chain spec of the Hoodi testnet.
+    pub fn hoodi() -> Self {
+        Self::builtin(CHAIN_ID_HOODI, GENESIS_HASH_HOODI, ForkSchedule::HOODI, 0)
+    }
+
+    /// Looks up the chain spec for a chain id.
+    ///
+    /// Specs registered through [`register_chain_spec`] take precedence over the built-in ones, so a
+    /// caller can also override the schedule of a known network.
+    ///
+    /// # Returns
+    ///
+    /// `Some(ChainSpec)` if the chain is registered or built in, `None` otherwise.
+    pub fn from_chain_id(chain_id: u64) -> Option<Self> {
+        if let Some(spec) = registry()
+            .read()
+            .expect("chain spec registry poisoned")
+            .get(&chain_id)
+        {
+            return Some(spec.clone());
+        }
+
+        match chain_id {
+            CHAIN_ID_MAINNET => Some(Self::mainnet()),
+            CHAIN_ID_SEPOLIA => Some(Self::sepolia()),
+            CHAIN_ID_HOLESKY => Some(Self::holesky()),
+            CHAIN_ID_HOODI => Some(Self::hoodi()),
+            _ => None,
+        }
+    }
+
+    /// Looks up the chain spec for a chain id, failing with `VerificationError::UnknownChain` if the
+    /// chain is neither registered nor built in.
+    pub fn try_from_chain_id(chain_id: u64) -> Result<Self, VerificationError> {
+        Self::from_chain_id(chain_id).ok_or(VerificationError::UnknownChain(chain_id))
+    }
+
+    fn builtin(
+        chain_id: u64,
+        genesis_hash: &str,
+        forks: ForkSchedule,
+        terminal_total_difficulty: u128,
+    ) -> Self {
+        ChainSpec {
+            chain_id,
+            genesis_hash: Some(
+                H256::from_str(genesis_hash).expect("invalid built-in genesis hash"),
+            ),
+            forks,
+            terminal_total_difficulty: Some(U256::from(terminal_total_difficulty)),
+        }
+    }
+}
+
+fn registry() -> &'static RwLock<HashMap<u64, ChainSpec>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<u64, ChainSpec>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Registers a chain spec so it can be found with [`ChainSpec::from_chain_id`].
+///
+/// Registering a spec for a chain id that is already registered replaces the previous spec.
+pub fn register_chain_spec(spec: ChainSpec) {
+    registry()
+        .write()
+        .expect("chain spec registry poisoned")
+        .insert(spec.chain_id, spec);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eras::Era;
+
+    #[test]
+    fn test_builtin_specs() {
+        for chain_id in [
+            CHAIN_ID_MAINNET,
+            CHAIN_ID_SEPOLIA,
+            CHAIN_ID_HOLESKY,
+            CHAIN_ID_HOODI,
+        ] {
+            let spec = ChainSpec::from_chain_id(chain_id).expect("missing built-in spec");
+            assert_eq!(spec.chain_id, chain_id);
+            assert!(spec.genesis_hash.is_some());
+        }
+        assert_eq!(
+            ChainSpec::mainnet().terminal_total_difficulty,
+            Some(U256::from(TERMINAL_TOTAL_DIFFICULTY))
+        );
+        assert_eq!(
+            ChainSpec::try_from_chain_id(1337),
+            Err(VerificationError::UnknownChain(1337))
+        );
+    }
+
+    #[test]
+    fn test_register_custom_spec() {
+        let devnet = ChainSpec {
+            chain_id: 3_151_908,
+            genesis_hash: None,
+            forks: ForkSchedule {
+                london_block: Some(0),
+                paris_block: Some(0),
+                shanghai_time: Some(0),
+                cancun_time: Some(0),
+                prague_time: Some(1_000),
+                ..Default::default()
+            },
+            terminal_total_difficulty: Some(U256::zero()),
+        };
+        register_chain_spec(devnet.clone());
+
+        let spec = ChainSpec::from_chain_id(devnet.chain_id).expect("spec not registered");
+        assert_eq!(spec, devnet);
+        assert_eq!(spec.forks.era_at(10, 999), Era::Dencun);
+        assert_eq!(spec.forks.era_at(11, 1_000), Era::Pectra);
+    }
+}
@@ -0,0 +1,143 @@
+use super::ConsensusError;
+use crate::eras::ForkSchedule;
+use eth_rlp_types::ExecutionHeader;
+
+/// The base fee of the first London block, in wei (EIP-1559).
+pub const INITIAL_BASE_FEE: u64 = 1_000_000_000;
+
+/// The ratio between a block's gas limit and its gas target (EIP-1559).
+pub const ELASTICITY_MULTIPLIER: u64 = 2;
+
+/// Bounds how much the base fee can change from one block to the next (EIP-1559).
+pub const BASE_FEE_MAX_CHANGE_DENOMINATOR: u64 = 8;
+
+/// Computes the base fee a block must carry given its parent, following EIP-1559.
+///
+/// The base fee moves towards the parent's gas target (half its gas limit): it rises when the parent used
+/// more gas than the target and falls when it used less, by at most 1/8 per block.
+///
+/// # Arguments
+///
+/// - `parent`: The parent of the block whose base fee is computed.
+/// - `forks`: The fork schedule of the chain, used to detect the London activation block.
+///
+/// # Returns
+///
+/// `Some(base_fee)` for London and later blocks, `None` for blocks before London, which carry no base fee.
+/// The first London block gets `INITIAL_BASE_FEE`.
+pub fn calc_next_base_fee(parent: &ExecutionHeader, forks: &ForkSchedule) -> Option<u64> {
+    let london_block = forks.london_block?;
+    let number = parent.number + 1;
+    if number < london_block {
+        return None;
+    }
+    // The parent of the first London block has no base fee to start from.
+    let parent_base_fee = match parent.base_fee_per_gas {
+        Some(base_fee) if number != london_block => base_fee as u128,
+        _ => return Some(INITIAL_BASE_FEE),
+    };
+
+    let gas_target = (parent.gas_limit / ELASTICITY_MULTIPLIER) as u128;
+    let gas_used = parent.gas_used as u128;
+    let denominator = BASE_FEE_MAX_CHANGE_DENOMINATOR as u128;
+
+    let base_fee = if gas_target == 0 || gas_used == gas_target {
+        parent_base_fee
+    } else if gas_used > gas_target {
+        let delta = (parent_base_fee * (gas_used - gas_target) / gas_target / denominator).max(1);
+        parent_base_fee + delta
+    } else {
+        let delta = parent_base_fee * (gas_target - gas_used) / gas_target / denominator;
+        parent_base_fee.saturating_sub(delta)
+    };
+
+    Some(base_fee.min(u64::MAX as u128) as u64)
+}
+
+/// Checks that a block's base fee is the one EIP-1559 derives from its parent.
+///
+/// # Returns
+///
+/// `Ok(())` if `child` carries the expected base fee (or none, before London), or a `ConsensusError`
+/// describing the missing or mismatching base fee.
+pub fn validate_base_fee(
+    parent: &ExecutionHeader,
+    child: &ExecutionHeader,
+    forks: &ForkSchedule,
+) -> Result<(), ConsensusError> {
+    let Some(expected) = calc_next_base_fee(parent, forks) else {
+        return Ok(());
+    };
+    let found = child
+        .base_fee_per_gas
+        .ok_or(ConsensusError::MissingBaseFee)?;
+
+    if found != expected {
+        return Err(ConsensusError::BaseFeeMismatch { expected, found });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parent(gas_used: u64) -> ExecutionHeader {
+        ExecutionHeader {
+            number: 20_000_000,
+            gas_limit: 30_000_000,
+            gas_used,
+            base_fee_per_gas: Some(10_000_000_000),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_calc_next_base_fee() {
+        let forks = ForkSchedule::MAINNET;
+
+        // On target, full and empty parent blocks.
+        assert_eq!(
+            calc_next_base_fee(&parent(15_000_000), &forks),
+            Some(10_000_000_000)
+        );
+        assert_eq!(
+            calc_next_base_fee(&parent(30_000_000), &forks),
+            Some(11_250_000_000)
+        );
+        assert_eq!(calc_next_base_fee(&parent(0), &forks), Some(8_750_000_000));
+
+        let mut last_pow_block = parent(0);
+        last_pow_block.number = forks.london_block.unwrap() - 1;
+        last_pow_block.base_fee_per_gas = None;
+        assert_eq!(
+            calc_next_base_fee(&last_pow_block, &forks),
+            Some(INITIAL_BASE_FEE)
+        );
+        last_pow_block.number -= 1;
+        assert_eq!(calc_next_base_fee(&last_pow_block, &forks), None);
+    }
+
+    #[test]
+    fn test_validate_base_fee() {
+        let forks = ForkSchedule::MAINNET;
+        let mut child = ExecutionHeader {
+            number: 20_000_001,
+            base_fee_per_gas: Some(11_250_000_000),
+            ..Default::default()
+        };
+        assert_eq!(
+            validate_base_fee(&parent(30_000_000), &child, &forks),
+            Ok(())
+        );
+
+        child.base_fee_per_gas = Some(11_000_000_000);
+        assert_eq!(
+            validate_base_fee(&parent(30_000_000), &child, &forks),
+            Err(ConsensusError::BaseFeeMismatch {
+                expected: 11_250_000_000,
+                found: 11_000_000_000,
+            })
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use super::ConsensusError;
+use crate::eras::{Era, ForkSchedule};
+use eth_rlp_types::ExecutionHeader;
+use ethereum_types::U256;
+
+/// The blob gas consumed by a single blob (EIP-4844).
+pub const GAS_PER_BLOB: u64 = 131_072;
+
+/// The smallest blob base fee, in wei (EIP-4844).
+pub const MIN_BLOB_BASE_FEE: u64 = 1;
+
+/// The blob gas parameters of a fork.
+///
+/// # Fields
+///
+/// - `target_blob_gas_per_block`: The blob gas a block should use on average; usage above it raises the excess.
+/// - `max_blob_gas_per_block`: The most blob gas a single block may use.
+/// - `base_fee_update_fraction`: Controls how fast the blob base fee reacts to the excess blob gas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlobParams {
+    pub target_blob_gas_per_block: u64,
+    pub max_blob_gas_per_block: u64,
+    pub base_fee_update_fraction: u64,
+}
+
+impl BlobParams {
+    /// The blob parameters introduced by Cancun (EIP-4844): a target of 3 and a maximum of 6 blobs.
+    pub const CANCUN: BlobParams = BlobParams {
+        target_blob_gas_per_block: 3 * GAS_PER_BLOB,
+        max_blob_gas_per_block: 6 * GAS_PER_BLOB,
+        base_fee_update_fraction: 3_338_477,
+    };
+
+    /// The blob parameters raised by Prague (EIP-7691): a target of 6 and a maximum of 9 blobs.
+    pub const PRAGUE: BlobParams = BlobParams {
+        target_blob_gas_per_block: 6 * GAS_PER_BLOB,
+        max_blob_gas_per_block: 9 * GAS_PER_BLOB,
+        base_fee_update_fraction: 5_007_716,
+    };
+
+    /// Returns the blob parameters in force during an era, or `None` before blobs were introduced.
+    pub fn for_era(era: Era) -> Option<BlobParams> {
+        match era {
+            Era::Dencun => Some(Self::CANCUN),
+            Era::Pectra => Some(Self::PRAGUE),
+            _ => None,
+        }
+    }
+}
+
+/// Computes the excess blob gas of a block from its parent's blob gas accounting (EIP-4844).
+///
+/// The excess accumulates the blob gas used above the target: `parent_excess + parent_used - target`,
+/// floored at zero. The target is the one of the fork the child block belongs to.
+pub fn calc_excess_blob_gas(
+    parent_excess_blob_gas: u64,
+    parent_blob_gas_used: u64,
+    params: &BlobParams,
+) -> u64 {
+    (parent_excess_blob_gas + parent_blob_gas_used).saturating_sub(params.target_blob_gas_per_block)
+}
+
+/// Approximates `factor * e ** (numerator / denominator)` using integer arithmetic, as specified by
+/// EIP-4844. Saturates at `U256::MAX` instead of overflowing.
+pub fn fake_exponential(factor: u64, numerator: u64, denominator: u64) -> U256 {
+    let numerator = U256::from(numerator);
+    let denominator = U256::from(denominator);
+
+    let mut i = U256::one();
+    let mut output = U256::zero();
+    let mut accumulator = U256::from(factor) * denominator;
+    while !accumulator.is_zero() {
+        output = output.saturating_add(accumulator);
+        accumulator = match accumulator.checked_mul(numerator) {
+            Some(product) => product / (denominator * i),
+            None => return U256::MAX,
+        };
+        i += U256::one();
+    }
+    output / denominator
+}
+
+/// Computes the blob base fee of a block from its excess blob gas.
+///
+/// # Returns
+///
+/// The blob base fee in wei, or `None` if the block predates Cancun or carries no excess blob gas.
+pub fn blob_base_fee(header: &ExecutionHeader, forks: &ForkSchedule) -> Option<U256> {
+    let params = BlobParams::for_era(forks.era_at(header.number, header.timestamp))?;
+    let excess_blob_gas = header.excess_blob_gas?;
+    Some(fake_exponential(
+        MIN_BLOB_BASE_FEE,
+        excess_blob_gas,
+        params.base_fee_update_fraction,
+    ))
+}
+
+/// Checks the rules on a block's blob gas that do not depend on its parent.
+///
+/// From Cancun on, the blob gas fields must be present and the blob gas used must be a multiple of
+/// `GAS_PER_BLOB` and at most the fork's maximum. Blocks before Cancun are not checked.
+///
+/// # Returns
+///
+/// `Ok(())` if the blob gas rules hold, or a `ConsensusError` naming the rule that is broken.
+pub fn validate_blob_gas_usage(
+    header: &ExecutionHeader,
+    forks: &ForkSchedule,
+) -> Result<(), ConsensusError> {
+    let Some(params) = BlobParams::for_era(forks.era_at(header.number, header.timestamp)) else {
+        return Ok(());
+    };
+    let (Some(blob_gas_used), Some(_)) = (header.blob_gas_used, header.excess_blob_gas) else {
+        return Err(ConsensusError::MissingBlobGasFields);
+    };
+
+    if !blob_gas_used.is_multiple_of(GAS_PER_BLOB) {
+        return Err(ConsensusError::BlobGasUsedNotMultipleOfBlobSize { blob_gas_used });
+    }
+    if blob_gas_used > params.max_blob_gas_per_block {
+        return Err(ConsensusError::BlobGasUsedExceedsMax {
+            blob_gas_used,
+            max_blob_gas: params.max_blob_gas_per_block,
+        });
+    }
+    Ok(())
+}
+
+/// Checks a block's blob gas accounting against its parent.
+///
+/// The block must pass [`validate_blob_gas_usage`], and the excess blob gas must follow from the parent's with [`calc_excess_blob_gas`]. A parent from before
+/// Cancun counts as having neither used nor accumulated blob gas. Blocks before Cancun are not checked.
+///
+/// # Returns
+///
+/// `Ok(())` if the blob gas rules hold, or a `ConsensusError` naming the rule that is broken.
+pub fn validate_blob_gas(
+    parent: &ExecutionHeader,
+    child: &ExecutionHeader,
+    forks: &ForkSchedule,
+) -> Result<(), ConsensusError> {
+    let Some(params) = BlobParams::for_era(forks.era_at(child.number, child.timestamp)) else {
+        return Ok(());
+    };
+    validate_blob_gas_usage(child, forks)?;
+    let excess_blob_gas = child.excess_blob_gas.unwrap_or_default();
+
+    let expected = calc_excess_blob_gas(
+        parent.excess_blob_gas.unwrap_or_default(),
+        parent.blob_gas_used.unwrap_or_default(),
+        &params,
+    );
+    if excess_blob_gas != expected {
+        return Err(ConsensusError::ExcessBlobGasMismatch {
+            expected,
+            found: excess_blob_gas,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{CANCUN_TIMESTAMP, PRAGUE_TIMESTAMP};
+
+    #[test]
+    fn test_fake_exponential() {
+        for (factor, numerator, denominator, expected) in [
+            (1, 0, 1, 1u64),
+            (38_493, 0, 1_000, 38_493),
+            (1, 2, 1, 6),
+            (1, 4, 2, 6),
+            (1, 3, 1, 16),
+            (10, 8, 2, 542),
+            (1, 50_000_000, 2_225_652, 5_709_098_764),
+        ] {
+            assert_eq!(
+                fake_exponential(factor, numerator, denominator),
+                U256::from(expected)
+            );
+        }
+        assert_eq!(fake_exponential(1, u64::MAX, 1), U256::MAX);
+    }
+
+    #[test]
+    fn test_validate_blob_gas_per_fork() {
+        let forks = ForkSchedule::MAINNET;
+        let parent = ExecutionHeader {
+            number: 22_000_000,
+            timestamp: CANCUN_TIMESTAMP + 12,
+            blob_gas_used: Some(6 * GAS_PER_BLOB),
+            excess_blob_gas: Some(GAS_PER_BLOB),
+            ..Default::default()
+        };
+        let mut child = ExecutionHeader {
+            number: 22_000_001,
+            timestamp: CANCUN_TIMESTAMP + 24,
+            blob_gas_used: Some(0),
+            excess_blob_gas: Some(4 * GAS_PER_BLOB),
+            ..Default::default()
+        };
+        assert_eq!(validate_blob_gas(&parent, &child, &forks), Ok(()));
+        assert_eq!(
+            blob_base_fee(&child, &forks),
+            Some(fake_exponential(1, 4 * GAS_PER_BLOB, 3_338_477))
+        );
+
+        // After Prague the target doubles to six blobs, so the same parent adds one blob of excess.
+        child.timestamp = PRAGUE_TIMESTAMP;
+        assert_eq!(
+            validate_blob_gas(&parent, &child, &forks),
+            Err(ConsensusError::ExcessBlobGasMismatch {
+                expected: GAS_PER_BLOB,
+                found: 4 * GAS_PER_BLOB,
+            })
+        );
+
+        child.excess_blob_gas = Some(GAS_PER_BLOB);
+        child.blob_gas_used = Some(10 * GAS_PER_BLOB);
+        assert_eq!(
+            validate_blob_gas(&parent, &child, &forks),
+            Err(ConsensusError::BlobGasUsedExceedsMax {
+                blob_gas_used: 10 * GAS_PER_BLOB,
+                max_blob_gas: 9 * GAS_PER_BLOB,
+            })
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use super::ConsensusError;
+use crate::constants::EMPTY_OMMERS_HASH;
+use crate::eras::{Era, ForkSchedule};
+use eth_rlp_types::ExecutionHeader;
+use ethereum_types::{H256, U256};
+use std::str::FromStr;
+
+/// The lowest difficulty a proof-of-work block may have.
+pub const MINIMUM_DIFFICULTY: u64 = 131_072;
+
+/// Bounds the difficulty adjustment: each step moves the difficulty by `parent_difficulty / 2048`.
+pub const DIFFICULTY_BOUND_DIVISOR: u64 = 2048;
+
+/// The number of blocks after which the difficulty bomb doubles.
+pub const EXP_DIFF_PERIOD: u64 = 100_000;
+
+/// Returns how many blocks the difficulty bomb is pushed back for `block_number`, according to the
+/// most recent bomb-delaying fork active at that block.
+pub fn bomb_delay(block_number: u64, forks: &ForkSchedule) -> u64 {
+    let delays = [
+        (forks.gray_glacier_block, 11_400_000),
+        (forks.arrow_glacier_block, 10_700_000),
+        (forks.london_block, 9_700_000),
+        (forks.muir_glacier_block, 9_000_000),
+        (forks.constantinople_block, 5_000_000),
+        (forks.byzantium_block, 3_000_000),
+    ];
+    delays
+        .iter()
+        .find(|(activation, _)| activation.is_some_and(|b| block_number >= b))
+        .map_or(0, |&(_, delay)| delay)
+}
+
+/// Computes the difficulty a proof-of-work block must have, given its parent and its own timestamp.
+///
+/// The difficulty moves towards a target block time in steps of `parent_difficulty / 2048`: Frontier
+/// compares the block time to 13 seconds, Homestead (EIP-2) scales the step with the block time, and
+/// Byzantium (EIP-100) also rewards parents that included ommers. On top of that, the difficulty bomb
+/// adds `2^(period - 2)` every `EXP_DIFF_PERIOD` blocks, counted from the block number minus the
+/// active bomb delay from Byzantium onwards.
+///
+/// # Arguments
+///
+/// - `parent`: The parent of the block.
+/// - `timestamp`: The timestamp of the block.
+/// - `forks`: The fork schedule of the chain, which selects the adjustment rule and bomb delay.
+///
+/// # Returns
+///
+/// The expected difficulty of the block following `parent`.
+pub fn calc_difficulty(parent: &ExecutionHeader, timestamp: u64, forks: &ForkSchedule) -> U256 {
+    let number = parent.number + 1;
+    let active = |activation: Option<u64>| activation.is_some_and(|b| number >= b);
+    let step = parent.difficulty / DIFFICULTY_BOUND_DIVISOR;
+    let block_time = timestamp.saturating_sub(parent.timestamp);
+
+    // The adjustment factor, in multiples of `step`, is clamped at -99 from Homestead onwards.
+    let factor: i64 = if active(forks.byzantium_block) {
+        let ommers = if parent.ommers_hash == empty_ommers_hash() {
+            1
+        } else {
+            2
+        };
+        (ommers - (block_time / 9) as i64).max(-99)
+    } else if active(forks.homestead_block) {
+        (1 - (block_time / 10) as i64).max(-99)
+    } else if block_time < 13 {
+        1
+    } else {
+        -1
+    };
+
+    let adjustment = step * U256::from(factor.unsigned_abs());
+    let mut difficulty = if factor >= 0 {
+        parent.difficulty + adjustment
+    } else {
+        parent.difficulty.saturating_sub(adjustment)
+    };
+    difficulty = difficulty.max(U256::from(MINIMUM_DIFFICULTY));
+
+    let period = number.saturating_sub(bomb_delay(number, forks)) / EXP_DIFF_PERIOD;
+    if period > 1 {
+        difficulty += U256::one() << (period - 2);
+    }
+    difficulty
+}
+
+/// Checks the difficulty of a proof-of-work block against the one derived from its parent.
+///
+/// Proof-of-stake blocks are skipped, since their difficulty is fixed at zero and checked as a header
+/// invariant instead.
+///
+/// # Returns
+///
+/// `Ok(())` if the difficulty matches or the block is past the Merge, or
+/// `ConsensusError::DifficultyMismatch` otherwise.
+pub fn validate_difficulty(
+    parent: &ExecutionHeader,
+    child: &ExecutionHeader,
+    forks: &ForkSchedule,
+) -> Result<(), ConsensusError> {
+    if forks.era_at(child.number, child.timestamp) >= Era::Paris {
+        return Ok(());
+    }
+
+    let expected = calc_difficulty(parent, child.timestamp, forks);
+    if child.difficulty != expected {
+        return Err(ConsensusError::DifficultyMismatch {
+            expected,
+            found: child.difficulty,
+        });
+    }
+    Ok(())
+}
+
+/// Checks that a block's total difficulty is its parent's total difficulty plus its own difficulty.
+///
+/// # Returns
+///
+/// `Ok(())` if the total difficulty accumulates correctly, or `ConsensusError::TotalDifficultyMismatch`
+/// otherwise.
+pub fn validate_total_difficulty(
+    parent_total_difficulty: U256,
+    child: &ExecutionHeader,
+    child_total_difficulty: U256,
+) -> Result<(), ConsensusError> {
+    let expected = parent_total_difficulty.saturating_add(child.difficulty);
+    if child_total_difficulty != expected {
+        return Err(ConsensusError::TotalDifficultyMismatch {
+            expected,
+            found: child_total_difficulty,
+        });
+    }
+    Ok(())
+}
+
+fn empty_ommers_hash() -> H256 {
+    H256::from_str(EMPTY_OMMERS_HASH).expect("EMPTY_OMMERS_HASH is a valid hash")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(number: u64, timestamp: u64, difficulty: u64) -> ExecutionHeader {
+        ExecutionHeader {
+            number,
+            timestamp,
+            difficulty: U256::from(difficulty),
+            ommers_hash: empty_ommers_hash(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_calc_difficulty_per_fork() {
+        let forks = ForkSchedule::MAINNET;
+
+        // Frontier: +1 step below 13 seconds, -1 step otherwise; block 200,000 already feels the bomb.
+        let parent = header(199_999, 1_000, 20_480_000);
+        assert_eq!(
+            calc_difficulty(&parent, 1_012, &forks),
+            U256::from(20_490_001)
+        );
+        assert_eq!(
+            calc_difficulty(&parent, 1_013, &forks),
+            U256::from(20_470_001)
+        );
+
+        // Homestead: the step scales with the block time.
+        let parent = header(1_200_000, 1_000, 20_480_000);
+        assert_eq!(
+            calc_difficulty(&parent, 1_035, &forks),
+            U256::from(20_480_000 - 2 * 10_000 + (1 << 10))
+        );
+
+        // Byzantium: a parent with ommers pushes the difficulty up; the bomb is delayed by 3 million.
+        let mut parent = header(4_400_000, 1_000, 20_480_000);
+        parent.ommers_hash = H256::repeat_byte(1);
+        assert_eq!(
+            calc_difficulty(&parent, 1_009, &forks),
+            U256::from(20_490_000 + (1 << 12))
+        );
+    }
+
+    #[test]
+    fn test_bomb_delay_per_fork() {
+        let forks = ForkSchedule::MAINNET;
+        assert_eq!(bomb_delay(4_369_999, &forks), 0);
+        assert_eq!(bomb_delay(7_280_000, &forks), 5_000_000);
+        assert_eq!(bomb_delay(12_965_000, &forks), 9_700_000);
+        assert_eq!(bomb_delay(15_050_000, &forks), 11_400_000);
+    }
+}
@@ -0,0 +1,339 @@
+use super::ConsensusError;
+use crate::hash::keccak256;
+use eth_rlp_types::ExecutionHeader;
+use ethereum_types::{H256, U512};
+use rlp::{Rlp, RlpStream};
+use tiny_keccak::{Hasher, Keccak};
+
+/// The number of blocks that share an Ethash cache and dataset.
+pub const EPOCH_LENGTH: u64 = 30_000;
+
+const WORD_BYTES: usize = 4;
+const HASH_BYTES: usize = 64;
+const MIX_BYTES: usize = 128;
+const HASH_WORDS: usize = HASH_BYTES / WORD_BYTES;
+const MIX_WORDS: usize = MIX_BYTES / WORD_BYTES;
+const CACHE_BYTES_INIT: u64 = 1 << 24;
+const CACHE_BYTES_GROWTH: u64 = 1 << 17;
+const DATASET_BYTES_INIT: u64 = 1 << 30;
+const DATASET_BYTES_GROWTH: u64 = 1 << 23;
+const CACHE_ROUNDS: usize = 3;
+const DATASET_PARENTS: u32 = 256;
+const ACCESSES: u32 = 64;
+const FNV_PRIME: u32 = 0x0100_0193;
+
+type Node = [u32; HASH_WORDS];
+
+/// Returns the size in bytes of the Ethash light cache for `epoch`.
+///
+/// The size grows linearly with the epoch and is rounded down so that the number of 64 byte items is
+/// prime, which keeps the pseudo-random accesses from falling into short cycles.
+pub fn cache_size(epoch: u64) -> u64 {
+    let mut size = CACHE_BYTES_INIT + CACHE_BYTES_GROWTH * epoch - HASH_BYTES as u64;
+    while !is_prime(size / HASH_BYTES as u64) {
+        size -= 2 * HASH_BYTES as u64;
+    }
+    size
+}
+
+/// Returns the size in bytes of the full Ethash dataset for `epoch`, rounded down the same way as
+/// [`cache_size`] but in 128 byte items.
+pub fn dataset_size(epoch: u64) -> u64 {
+    let mut size = DATASET_BYTES_INIT + DATASET_BYTES_GROWTH * epoch - MIX_BYTES as u64;
+    while !is_prime(size / MIX_BYTES as u64) {
+        size -= 2 * MIX_BYTES as u64;
+    }
+    size
+}
+
+/// Returns the seed of `epoch`: the Keccak256 hash applied `epoch` times to 32 zero bytes.
+pub fn seed_hash(epoch: u64) -> H256 {
+    let mut seed = [0u8; 32];
+    for _ in 0..epoch {
+        seed = keccak256(&seed).0;
+    }
+    H256(seed)
+}
+
+/// Returns the hash an Ethash seal commits to: the Keccak256 hash of the header's RLP list without
+/// its `mix_hash` and `nonce`.
+pub fn seal_hash(header: &ExecutionHeader) -> H256 {
+    let encoded = rlp::encode(header);
+    let items: Vec<_> = Rlp::new(&encoded).iter().collect();
+
+    let mut stream = RlpStream::new_list(items.len() - 2);
+    for (i, item) in items.iter().enumerate() {
+        // Items 13 and 14 are the mix hash and the nonce.
+        if i != 13 && i != 14 {
+            stream.append_raw(item.as_raw(), 1);
+        }
+    }
+    keccak256(&stream.out())
+}
+
+/// The Ethash light cache of one epoch, from which any item of the full dataset can be derived.
+///
+/// Generating a cache takes a few seconds and tens of megabytes, so it should be reused for every
+/// header of its epoch; [`Ethash`] does that automatically.
+pub struct LightCache {
+    epoch: u64,
+    full_size: u64,
+    nodes: Vec<Node>,
+}
+
+impl LightCache {
+    /// Generates the light cache of `epoch` from the epoch's seed.
+    pub fn new(epoch: u64) -> Self {
+        Self::generate(epoch, cache_size(epoch), dataset_size(epoch))
+    }
+
+    fn generate(epoch: u64, cache_size: u64, full_size: u64) -> Self {
+        let count = (cache_size / HASH_BYTES as u64) as usize;
+
+        let mut nodes = Vec::with_capacity(count);
+        nodes.push(keccak512_words(&seed_hash(epoch).0));
+        for i in 1..count {
+            nodes.push(keccak512_words(&words_to_bytes(&nodes[i - 1])));
+        }
+
+        for _ in 0..CACHE_ROUNDS {
+            for i in 0..count {
+                let other = nodes[i][0] as usize % count;
+                let previous = nodes[(i + count - 1) % count];
+                let mut mixed = [0u32; HASH_WORDS];
+                for (w, word) in mixed.iter_mut().enumerate() {
+                    *word = previous[w] ^ nodes[other][w];
+                }
+                nodes[i] = keccak512_words(&words_to_bytes(&mixed));
+            }
+        }
+
+        LightCache {
+            epoch,
+            full_size,
+            nodes,
+        }
+    }
+
+    /// Returns the epoch this cache belongs to.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Runs the Ethash hashimoto loop for a seal hash and nonce, deriving the dataset items it touches
+    /// from the cache.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the mix digest, which a valid header stores as its `mix_hash`, and the final hash,
+    /// which must not exceed `2^256 / difficulty`.
+    pub fn hashimoto(&self, seal_hash: H256, nonce: u64) -> (H256, H256) {
+        let mut seed_input = [0u8; 40];
+        seed_input[..32].copy_from_slice(seal_hash.as_bytes());
+        seed_input[32..].copy_from_slice(&nonce.to_le_bytes());
+        let seed = keccak512_words(&seed_input);
+
+        let mut mix = [0u32; MIX_WORDS];
+        for (i, word) in mix.iter_mut().enumerate() {
+            *word = seed[i % HASH_WORDS];
+        }
+
+        let pages = (self.full_size / MIX_BYTES as u64) as u32;
+        for i in 0..ACCESSES {
+            let page = fnv(i ^ seed[0], mix[i as usize % MIX_WORDS]) % pages;
+            let first = self.dataset_item(page * 2);
+            let second = self.dataset_item(page * 2 + 1);
+            for (w, word) in mix.iter_mut().enumerate() {
+                let data = if w < HASH_WORDS {
+                    first[w]
+                } else {
+                    second[w - HASH_WORDS]
+                };
+                *word = fnv(*word, data);
+            }
+        }
+
+        let mut digest = [0u8; 32];
+        for (i, chunk) in mix.chunks(4).enumerate() {
+            let compressed = fnv(fnv(fnv(chunk[0], chunk[1]), chunk[2]), chunk[3]);
+            digest[i * 4..i * 4 + 4].copy_from_slice(&compressed.to_le_bytes());
+        }
+
+        let mut result_input = [0u8; HASH_BYTES + 32];
+        result_input[..HASH_BYTES].copy_from_slice(&words_to_bytes(&seed));
+        result_input[HASH_BYTES..].copy_from_slice(&digest);
+
+        (H256(digest), keccak256(&result_input))
+    }
+
+    fn dataset_item(&self, index: u32) -> Node {
+        let count = self.nodes.len();
+        let mut mix = self.nodes[index as usize % count];
+        mix[0] ^= index;
+        mix = keccak512_words(&words_to_bytes(&mix));
+
+        for parent in 0..DATASET_PARENTS {
+            let parent_index = fnv(index ^ parent, mix[parent as usize % HASH_WORDS]) as usize;
+            let node = &self.nodes[parent_index % count];
+            for (w, word) in mix.iter_mut().enumerate() {
+                *word = fnv(*word, node[w]);
+            }
+        }
+        keccak512_words(&words_to_bytes(&mix))
+    }
+}
+
+/// Verifies Ethash proof-of-work seals, keeping the light cache of the last epoch it was used for.
+///
+/// Headers are best checked in block order so the cache only has to be regenerated once per epoch.
+#[derive(Default)]
+pub struct Ethash {
+    cache: Option<LightCache>,
+}
+
+impl Ethash {
+    /// Creates a verifier without any cache; the first header of each epoch generates one.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Verifies the proof-of-work seal of a pre-Merge header.
+    ///
+    /// The seal is valid when hashimoto, run over the header's seal hash and nonce, yields the header's
+    /// `mix_hash` and a final hash of at most `2^256 / difficulty`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` for a valid seal, `ConsensusError::InvalidMixHash` if the mix digest differs, or
+    /// `ConsensusError::InsufficientWork` if the final hash is above the target. A proof-of-stake header
+    /// has a zero difficulty and therefore never has sufficient work.
+    pub fn verify_seal(&mut self, header: &ExecutionHeader) -> Result<(), ConsensusError> {
+        if header.difficulty.is_zero() {
+            return Err(ConsensusError::InsufficientWork {
+                difficulty: header.difficulty,
+            });
+        }
+
+        let epoch = header.number / EPOCH_LENGTH;
+        let cache = match self.cache.take() {
+            Some(cache) if cache.epoch() == epoch => cache,
+            _ => LightCache::new(epoch),
+        };
+        let cache = self.cache.insert(cache);
+
+        let nonce = header.nonce.to_low_u64_be();
+        let (mix_hash, result) = cache.hashimoto(seal_hash(header), nonce);
+        check_seal(header, mix_hash, result)
+    }
+}
+
+fn check_seal(
+    header: &ExecutionHeader,
+    mix_hash: H256,
+    result: H256,
+) -> Result<(), ConsensusError> {
+    if mix_hash != header.mix_hash {
+        return Err(ConsensusError::InvalidMixHash {
+            expected: mix_hash,
+            found: header.mix_hash,
+        });
+    }
+    // result <= 2^256 / difficulty, without the division.
+    let work = U512::from_big_endian(result.as_bytes()) * U512::from(header.difficulty);
+    if work > U512::one() << 256 {
+        return Err(ConsensusError::InsufficientWork {
+            difficulty: header.difficulty,
+        });
+    }
+    Ok(())
+}
+
+fn fnv(a: u32, b: u32) -> u32 {
+    a.wrapping_mul(FNV_PRIME) ^ b
+}
+
+fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut d = 2;
+    while d * d <= n {
+        if n.is_multiple_of(d) {
+            return false;
+        }
+        d += 1;
+    }
+    true
+}
+
+fn keccak512_words(data: &[u8]) -> Node {
+    let mut hasher = Keccak::v512();
+    let mut output = [0u8; HASH_BYTES];
+    hasher.update(data);
+    hasher.finalize(&mut output);
+
+    let mut words = [0u32; HASH_WORDS];
+    for (word, chunk) in words.iter_mut().zip(output.chunks(WORD_BYTES)) {
+        *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+    words
+}
+
+fn words_to_bytes(words: &Node) -> [u8; HASH_BYTES] {
+    let mut bytes = [0u8; HASH_BYTES];
+    for (chunk, word) in bytes.chunks_mut(WORD_BYTES).zip(words) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::create_test_block_header_london;
+    use ethereum_types::{H64, U256};
+
+    #[test]
+    fn test_epoch_sizes() {
+        assert_eq!(cache_size(0), 16_776_896);
+        assert_eq!(dataset_size(0), 1_073_739_904);
+        assert_eq!(cache_size(1), 16_907_456);
+        assert_eq!(dataset_size(1), 1_082_130_304);
+    }
+
+    #[test]
+    fn test_check_seal_on_small_cache() {
+        // A cache of a few items exercises the same code paths as a real one in a fraction of the time.
+        let cache = LightCache::generate(0, 64 * 67, 128 * 67);
+        let mut header = ExecutionHeader {
+            difficulty: U256::one(),
+            number: 1,
+            nonce: H64::from_low_u64_be(0x0102_0304_0506_0708),
+            ..Default::default()
+        };
+        let (mix_hash, result) = cache.hashimoto(seal_hash(&header), header.nonce.to_low_u64_be());
+        header.mix_hash = mix_hash;
+        assert_eq!(check_seal(&header, mix_hash, result), Ok(()));
+
+        header.difficulty = U256::MAX;
+        assert_eq!(
+            check_seal(&header, mix_hash, result),
+            Err(ConsensusError::InsufficientWork {
+                difficulty: U256::MAX
+            })
+        );
+
+        let (other_mix, _) = cache.hashimoto(seal_hash(&header), 0);
+        assert!(matches!(
+            check_seal(&header, other_mix, result),
+            Err(ConsensusError::InvalidMixHash { .. })
+        ));
+    }
+
+    #[test]
+    #[ignore = "generates a full light cache for epoch 432, which is slow outside release builds"]
+    fn test_verify_seal_mainnet_london() {
+        let header = ExecutionHeader::try_from(&create_test_block_header_london()).unwrap();
+        assert_eq!(Ethash::new().verify_seal(&header), Ok(()));
+    }
+}
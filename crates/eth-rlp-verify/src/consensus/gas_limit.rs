@@ -0,0 +1,130 @@
+use super::{ConsensusError, ELASTICITY_MULTIPLIER};
+use crate::eras::ForkSchedule;
+use eth_rlp_types::ExecutionHeader;
+
+/// Bounds how much the gas limit can change from one block to the next: strictly less than
+/// `parent_gas_limit / GAS_LIMIT_BOUND_DIVISOR`.
+pub const GAS_LIMIT_BOUND_DIVISOR: u64 = 1024;
+
+/// The smallest gas limit a block may have.
+pub const MIN_GAS_LIMIT: u64 = 5000;
+
+/// Checks the rules on a block's gas limit and gas usage that do not depend on its parent.
+///
+/// A block may not use more gas than its limit, and its limit may not drop below `MIN_GAS_LIMIT`.
+///
+/// # Returns
+///
+/// `Ok(())` if the gas rules hold, or a `ConsensusError` naming the rule that is broken.
+pub fn validate_gas_usage(header: &ExecutionHeader) -> Result<(), ConsensusError> {
+    if header.gas_used > header.gas_limit {
+        return Err(ConsensusError::GasUsedExceedsLimit {
+            gas_used: header.gas_used,
+            gas_limit: header.gas_limit,
+        });
+    }
+    if header.gas_limit < MIN_GAS_LIMIT {
+        return Err(ConsensusError::GasLimitTooLow {
+            gas_limit: header.gas_limit,
+        });
+    }
+    Ok(())
+}
+
+/// Checks a block's gas limit and gas usage against its parent.
+///
+/// The block must pass [`validate_gas_usage`], and its limit must stay strictly within `1/1024` of the parent's limit. At the London activation block the parent's
+/// limit is first multiplied by the elasticity multiplier, since EIP-1559 turned the former limit into
+/// the gas target.
+///
+/// # Arguments
+///
+/// - `parent`: The parent of `child`.
+/// - `child`: The header whose gas limit is checked.
+/// - `forks`: The fork schedule of the chain, used to detect the London activation block.
+///
+/// # Returns
+///
+/// `Ok(())` if the gas rules hold, or a `ConsensusError` naming the rule that is broken.
+pub fn validate_gas_limit(
+    parent: &ExecutionHeader,
+    child: &ExecutionHeader,
+    forks: &ForkSchedule,
+) -> Result<(), ConsensusError> {
+    validate_gas_usage(child)?;
+
+    let parent_gas_limit = if forks.london_block == Some(child.number) {
+        parent.gas_limit.saturating_mul(ELASTICITY_MULTIPLIER)
+    } else {
+        parent.gas_limit
+    };
+
+    if child.gas_limit.abs_diff(parent_gas_limit) >= parent_gas_limit / GAS_LIMIT_BOUND_DIVISOR {
+        return Err(ConsensusError::GasLimitOutOfBounds {
+            parent_gas_limit,
+            gas_limit: child.gas_limit,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(number: u64, gas_limit: u64, gas_used: u64) -> ExecutionHeader {
+        ExecutionHeader {
+            number,
+            gas_limit,
+            gas_used,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_gas_limit() {
+        let forks = ForkSchedule::MAINNET;
+        let parent = header(20_000_000, 30_000_000, 0);
+
+        let max_step = 30_000_000 / GAS_LIMIT_BOUND_DIVISOR - 1;
+        for gas_limit in [30_000_000 + max_step, 30_000_000 - max_step] {
+            let child = header(20_000_001, gas_limit, 0);
+            assert_eq!(validate_gas_limit(&parent, &child, &forks), Ok(()));
+        }
+
+        let child = header(20_000_001, 30_000_000 + max_step + 1, 0);
+        assert_eq!(
+            validate_gas_limit(&parent, &child, &forks),
+            Err(ConsensusError::GasLimitOutOfBounds {
+                parent_gas_limit: 30_000_000,
+                gas_limit: 30_000_000 + max_step + 1,
+            })
+        );
+
+        let child = header(20_000_001, 30_000_000, 30_000_001);
+        assert_eq!(
+            validate_gas_limit(&parent, &child, &forks),
+            Err(ConsensusError::GasUsedExceedsLimit {
+                gas_used: 30_000_001,
+                gas_limit: 30_000_000,
+            })
+        );
+    }
+
+    #[test]
+    fn test_london_transition_doubles_parent_limit() {
+        let forks = ForkSchedule::MAINNET;
+        let london = forks.london_block.unwrap();
+        let parent = header(london - 1, 15_000_000, 0);
+
+        let child = header(london, 30_000_000, 0);
+        assert_eq!(validate_gas_limit(&parent, &child, &forks), Ok(()));
+
+        let child = header(london, 15_000_000, 0);
+        assert!(matches!(
+            validate_gas_limit(&parent, &child, &forks),
+            Err(ConsensusError::GasLimitOutOfBounds { .. })
+        ));
+    }
+}
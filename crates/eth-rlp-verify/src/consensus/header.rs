@@ -0,0 +1,90 @@
+use super::ConsensusError;
+use crate::constants::{EMPTY_OMMERS_HASH, MAX_EXTRA_DATA_SIZE};
+use crate::eras::Era;
+use eth_rlp_types::ExecutionHeader;
+use ethereum_types::H256;
+use std::str::FromStr;
+
+/// Checks the invariants a block header must satisfy on its own, given the era it belongs to.
+///
+/// In every era `extra_data` is at most `MAX_EXTRA_DATA_SIZE` bytes. From Paris on, proof-of-work
+/// fields are fixed: the difficulty and the nonce must be zero and the ommers hash must be the hash of
+/// an empty list (`EMPTY_OMMERS_HASH`).
+///
+/// # Arguments
+///
+/// - `header`: The header to check.
+/// - `era`: The era the header belongs to, e.g. from `ForkSchedule::era_at`.
+///
+/// # Returns
+///
+/// `Ok(())` if the header satisfies the invariants of its era, or the first `ConsensusError` found.
+pub fn validate_header_invariants(
+    header: &ExecutionHeader,
+    era: Era,
+) -> Result<(), ConsensusError> {
+    if header.extra_data.len() > MAX_EXTRA_DATA_SIZE {
+        return Err(ConsensusError::ExtraDataTooLong {
+            length: header.extra_data.len(),
+        });
+    }
+
+    if era >= Era::Paris {
+        if !header.difficulty.is_zero() {
+            return Err(ConsensusError::NonZeroDifficulty {
+                difficulty: header.difficulty,
+            });
+        }
+        if !header.nonce.is_zero() {
+            return Err(ConsensusError::NonZeroNonce {
+                nonce: header.nonce.to_low_u64_be(),
+            });
+        }
+        let empty_ommers_hash =
+            H256::from_str(EMPTY_OMMERS_HASH).expect("invalid empty ommers hash constant");
+        if header.ommers_hash != empty_ommers_hash {
+            return Err(ConsensusError::NonEmptyOmmers {
+                ommers_hash: header.ommers_hash,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::create_test_block_header_pectra_hoodi;
+    use ethereum_types::{H64, U256};
+
+    #[test]
+    fn test_post_merge_invariants() {
+        let header = ExecutionHeader::try_from(&create_test_block_header_pectra_hoodi()).unwrap();
+        assert_eq!(validate_header_invariants(&header, Era::Pectra), Ok(()));
+
+        let mut pow = header.clone();
+        pow.difficulty = U256::from(2);
+        pow.nonce = H64::from_low_u64_be(7);
+        assert_eq!(validate_header_invariants(&pow, Era::London), Ok(()));
+        assert_eq!(
+            validate_header_invariants(&pow, Era::Paris),
+            Err(ConsensusError::NonZeroDifficulty {
+                difficulty: U256::from(2)
+            })
+        );
+
+        pow.difficulty = U256::zero();
+        assert_eq!(
+            validate_header_invariants(&pow, Era::Shapella),
+            Err(ConsensusError::NonZeroNonce { nonce: 7 })
+        );
+
+        let mut long_extra_data = header;
+        long_extra_data.extra_data = vec![0; 33];
+        assert_eq!(
+            validate_header_invariants(&long_extra_data, Era::Genesis),
+            Err(ConsensusError::ExtraDataTooLong { length: 33 })
+        );
+    }
+}
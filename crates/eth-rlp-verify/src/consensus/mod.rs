@@ -0,0 +1,362 @@
+mod base_fee;
+mod blob_gas;
+mod difficulty;
+mod ethash;
+mod gas_limit;
+mod header;
+
+use crate::chain_spec::ChainSpec;
+use crate::constants::MAX_EXTRA_DATA_SIZE;
+use crate::ommers::MAX_OMMER_DEPTH;
+use eth_rlp_types::ExecutionHeader;
+use ethereum_types::{H256, U256};
+use std::fmt;
+
+// Re-export each consensus rule so they are accessible at the module level.
+pub use base_fee::{
+    calc_next_base_fee, validate_base_fee, BASE_FEE_MAX_CHANGE_DENOMINATOR, ELASTICITY_MULTIPLIER,
+    INITIAL_BASE_FEE,
+};
+pub use blob_gas::{
+    blob_base_fee, calc_excess_blob_gas, fake_exponential, validate_blob_gas,
+    validate_blob_gas_usage, BlobParams, GAS_PER_BLOB, MIN_BLOB_BASE_FEE,
+};
+pub use difficulty::{
+    bomb_delay, calc_difficulty, validate_difficulty, validate_total_difficulty,
+    DIFFICULTY_BOUND_DIVISOR, EXP_DIFF_PERIOD, MINIMUM_DIFFICULTY,
+};
+pub use ethash::{
+    cache_size, dataset_size, seal_hash, seed_hash, Ethash, LightCache, EPOCH_LENGTH,
+};
+pub use gas_limit::{
+    validate_gas_limit, validate_gas_usage, GAS_LIMIT_BOUND_DIVISOR, MIN_GAS_LIMIT,
+};
+pub use header::validate_header_invariants;
+
+/// Describes a consensus rule that a block header breaks, on its own or with respect to its parent.
+///
+/// Hash verification only proves that a header was not altered after its hash was taken; these rules
+/// prove that the header itself is one the protocol would have accepted.
+///
+/// # Variants
+///
+/// - `MissingBaseFee`: A London or later header has no `base_fee_per_gas`.
+/// - `BaseFeeMismatch`: The base fee differs from the one EIP-1559 derives from the parent.
+/// - `GasUsedExceedsLimit`: The header uses more gas than its gas limit allows.
+/// - `GasLimitOutOfBounds`: The gas limit moved by `1/1024` of the parent's limit or more.
+/// - `GasLimitTooLow`: The gas limit is below `MIN_GAS_LIMIT`.
+/// - `MissingBlobGasFields`: A Cancun or later header has no `blob_gas_used` or `excess_blob_gas`.
+/// - `BlobGasUsedNotMultipleOfBlobSize`: The blob gas used is not a whole number of blobs.
+/// - `BlobGasUsedExceedsMax`: The blob gas used is above the fork's per-block maximum.
+/// - `ExcessBlobGasMismatch`: The excess blob gas differs from the one derived from the parent.
+/// - `ExtraDataTooLong`: The `extra_data` is longer than `MAX_EXTRA_DATA_SIZE` bytes.
+/// - `NonZeroDifficulty`: A proof-of-stake header has a non-zero difficulty.
+/// - `NonZeroNonce`: A proof-of-stake header has a non-zero nonce.
+/// - `NonEmptyOmmers`: A proof-of-stake header commits to a non-empty list of ommers.
+/// - `DifficultyMismatch`: The difficulty of a proof-of-work header differs from the one derived from
+///   its parent.
+/// - `TotalDifficultyMismatch`: The total difficulty is not the parent's total difficulty plus the
+///   header's difficulty.
+/// - `TerminalTotalDifficultyNotReached`: The terminal proof-of-work block is below the chain's terminal
+///   total difficulty.
+/// - `TerminalTotalDifficultyAlreadyReached`: The parent of the terminal proof-of-work block had already
+///   reached the chain's terminal total difficulty.
+/// - `OmmersHashMismatch`: The hash of the ommer list differs from the block's `ommers_hash`.
+/// - `TooManyOmmers`: The block includes more ommers than allowed, which is none after the Merge.
+/// - `DuplicateOmmer`: The block includes the same ommer twice.
+/// - `OmmerIsAncestor`: The block includes its own parent as an ommer.
+/// - `OmmerDepthOutOfRange`: An ommer is not between one and `MAX_OMMER_DEPTH` blocks older than the block.
+/// - `InvalidMixHash`: The Ethash mix digest of a proof-of-work header differs from its `mix_hash`.
+/// - `InsufficientWork`: The Ethash result of a proof-of-work header is above `2^256 / difficulty`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConsensusError {
+    MissingBaseFee,
+    BaseFeeMismatch {
+        expected: u64,
+        found: u64,
+    },
+    GasUsedExceedsLimit {
+        gas_used: u64,
+        gas_limit: u64,
+    },
+    GasLimitOutOfBounds {
+        parent_gas_limit: u64,
+        gas_limit: u64,
+    },
+    GasLimitTooLow {
+        gas_limit: u64,
+    },
+    MissingBlobGasFields,
+    BlobGasUsedNotMultipleOfBlobSize {
+        blob_gas_used: u64,
+    },
+    BlobGasUsedExceedsMax {
+        blob_gas_used: u64,
+        max_blob_gas: u64,
+    },
+    ExcessBlobGasMismatch {
+        expected: u64,
+        found: u64,
+    },
+    ExtraDataTooLong {
+        length: usize,
+    },
+    NonZeroDifficulty {
+        difficulty: U256,
+    },
+    NonZeroNonce {
+        nonce: u64,
+    },
+    NonEmptyOmmers {
+        ommers_hash: H256,
+    },
+    DifficultyMismatch {
+        expected: U256,
+        found: U256,
+    },
+    TotalDifficultyMismatch {
+        expected: U256,
+        found: U256,
+    },
+    TerminalTotalDifficultyNotReached {
+        total_difficulty: U256,
+        terminal_total_difficulty: U256,
+    },
+    TerminalTotalDifficultyAlreadyReached {
+        total_difficulty: U256,
+        terminal_total_difficulty: U256,
+    },
+    OmmersHashMismatch {
+        expected: H256,
+        computed: H256,
+    },
+    TooManyOmmers {
+        count: usize,
+        max: usize,
+    },
+    DuplicateOmmer {
+        hash: H256,
+    },
+    OmmerIsAncestor {
+        hash: H256,
+    },
+    OmmerDepthOutOfRange {
+        block_number: u64,
+        ommer_number: u64,
+    },
+    InvalidMixHash {
+        expected: H256,
+        found: H256,
+    },
+    InsufficientWork {
+        difficulty: U256,
+    },
+}
+
+impl fmt::Display for ConsensusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConsensusError::MissingBaseFee => write!(f, "missing base fee"),
+            ConsensusError::BaseFeeMismatch { expected, found } => write!(
+                f,
+                "base fee mismatch: expected {}, found {}",
+                expected, found
+            ),
+            ConsensusError::GasUsedExceedsLimit {
+                gas_used,
+                gas_limit,
+            } => write!(f, "gas used {} exceeds gas limit {}", gas_used, gas_limit),
+            ConsensusError::GasLimitOutOfBounds {
+                parent_gas_limit,
+                gas_limit,
+            } => write!(
+                f,
+                "gas limit {} is out of bounds of parent gas limit {}",
+                gas_limit, parent_gas_limit
+            ),
+            ConsensusError::GasLimitTooLow { gas_limit } => write!(
+                f,
+                "gas limit {} is below the minimum of {}",
+                gas_limit, MIN_GAS_LIMIT
+            ),
+            ConsensusError::MissingBlobGasFields => write!(f, "missing blob gas fields"),
+            ConsensusError::BlobGasUsedNotMultipleOfBlobSize { blob_gas_used } => write!(
+                f,
+                "blob gas used {} is not a multiple of the blob gas per blob {}",
+                blob_gas_used, GAS_PER_BLOB
+            ),
+            ConsensusError::BlobGasUsedExceedsMax {
+                blob_gas_used,
+                max_blob_gas,
+            } => write!(
+                f,
+                "blob gas used {} exceeds the maximum of {}",
+                blob_gas_used, max_blob_gas
+            ),
+            ConsensusError::ExcessBlobGasMismatch { expected, found } => write!(
+                f,
+                "excess blob gas mismatch: expected {}, found {}",
+                expected, found
+            ),
+            ConsensusError::ExtraDataTooLong { length } => write!(
+                f,
+                "extra data is {} bytes long, more than the maximum of {}",
+                length, MAX_EXTRA_DATA_SIZE
+            ),
+            ConsensusError::NonZeroDifficulty { difficulty } => write!(
+                f,
+                "proof-of-stake block has non-zero difficulty {}",
+                difficulty
+            ),
+            ConsensusError::NonZeroNonce { nonce } => {
+                write!(f, "proof-of-stake block has non-zero nonce {:#018x}", nonce)
+            }
+            ConsensusError::NonEmptyOmmers { ommers_hash } => write!(
+                f,
+                "proof-of-stake block has non-empty ommers hash {:?}",
+                ommers_hash
+            ),
+            ConsensusError::DifficultyMismatch { expected, found } => write!(
+                f,
+                "difficulty mismatch: expected {}, found {}",
+                expected, found
+            ),
+            ConsensusError::TotalDifficultyMismatch { expected, found } => write!(
+                f,
+                "total difficulty mismatch: expected {}, found {}",
+                expected, found
+            ),
+            ConsensusError::TerminalTotalDifficultyNotReached {
+                total_difficulty,
+                terminal_total_difficulty,
+            } => write!(
+                f,
+                "terminal block total difficulty {} is below the terminal total difficulty {}",
+                total_difficulty, terminal_total_difficulty
+            ),
+            ConsensusError::TerminalTotalDifficultyAlreadyReached {
+                total_difficulty,
+                terminal_total_difficulty,
+            } => write!(
+                f,
+                "total difficulty {} already reached the terminal total difficulty {} before the terminal block",
+                total_difficulty, terminal_total_difficulty
+            ),
+            ConsensusError::OmmersHashMismatch { expected, computed } => write!(
+                f,
+                "ommers hash mismatch: expected {:?}, computed {:?}",
+                expected, computed
+            ),
+            ConsensusError::TooManyOmmers { count, max } => {
+                write!(f, "{} ommers included, at most {} allowed", count, max)
+            }
+            ConsensusError::DuplicateOmmer { hash } => {
+                write!(f, "ommer {:?} is included more than once", hash)
+            }
+            ConsensusError::OmmerIsAncestor { hash } => {
+                write!(f, "ommer {:?} is the parent of the block", hash)
+            }
+            ConsensusError::OmmerDepthOutOfRange {
+                block_number,
+                ommer_number,
+            } => write!(
+                f,
+                "ommer {} is not within {} blocks before block {}",
+                ommer_number, MAX_OMMER_DEPTH, block_number
+            ),
+            ConsensusError::InvalidMixHash { expected, found } => write!(
+                f,
+                "mix hash mismatch: expected {:?}, found {:?}",
+                expected, found
+            ),
+            ConsensusError::InsufficientWork { difficulty } => write!(
+                f,
+                "proof-of-work seal does not meet difficulty {}",
+                difficulty
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConsensusError {}
+
+/// Selects the consensus rules `are_blocks_and_chain_valid_with` enforces between consecutive headers,
+/// on top of hash verification and parent hash linkage.
+///
+/// Every check is disabled by default, which matches `are_blocks_and_chain_valid`.
+///
+/// # Fields
+///
+/// - `check_base_fee`: Recompute each header's EIP-1559 base fee from its parent.
+/// - `check_gas_limit`: Enforce the gas limit adjustment bounds and `gas_used <= gas_limit`.
+/// - `check_blob_gas`: Enforce the EIP-4844 blob gas accounting, with the blob parameters of each fork.
+/// - `check_header_invariants`: Enforce the invariants of each header's era on its own, such as a zero
+///   difficulty and nonce after the Merge. Unlike the other rules, this also applies to the first header.
+/// - `check_difficulty`: Recompute the difficulty of each proof-of-work header from its parent, with the
+///   adjustment rule and difficulty bomb delay of each fork.
+/// - `check_total_difficulty`: Require each `totaldifficulty` to be the previous one plus the header's
+///   difficulty. Headers without a stored total difficulty are skipped.
+/// - `check_continuity`: Require each block number to be one higher than the previous one and each
+///   timestamp to be strictly greater, reporting a `VerificationError::ContinuityBreak` otherwise.
+/// - `allow_gaps`: Accept headers that skip ahead in block number and verify the segments between such
+///   gaps independently. Takes precedence over `check_continuity` for gaps.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChainValidationOptions {
+    pub check_base_fee: bool,
+    pub check_gas_limit: bool,
+    pub check_blob_gas: bool,
+    pub check_header_invariants: bool,
+    pub check_difficulty: bool,
+    pub check_total_difficulty: bool,
+    pub check_continuity: bool,
+    pub allow_gaps: bool,
+}
+
+impl ChainValidationOptions {
+    /// Returns `true` if any rule needs the headers parsed into `ExecutionHeader`s.
+    pub(crate) fn checks_consensus(&self) -> bool {
+        self.check_base_fee
+            || self.check_gas_limit
+            || self.check_blob_gas
+            || self.check_header_invariants
+            || self.check_difficulty
+            || self.check_total_difficulty
+    }
+}
+
+/// Applies the consensus rules selected in `options` to a header and, unless it is the first header of
+/// the segment, its parent.
+pub(crate) fn validate_child(
+    parent: Option<&ExecutionHeader>,
+    child: &ExecutionHeader,
+    spec: &ChainSpec,
+    options: &ChainValidationOptions,
+) -> Result<(), ConsensusError> {
+    if options.check_header_invariants {
+        let era = spec.forks.era_at(child.number, child.timestamp);
+        validate_header_invariants(child, era)?;
+    }
+    // The first header of a segment has no parent, but the rules on its own fields still apply.
+    if options.check_gas_limit {
+        validate_gas_usage(child)?;
+    }
+    if options.check_blob_gas {
+        validate_blob_gas_usage(child, &spec.forks)?;
+    }
+    let Some(parent) = parent else {
+        return Ok(());
+    };
+    if options.check_base_fee {
+        validate_base_fee(parent, child, &spec.forks)?;
+    }
+    if options.check_gas_limit {
+        validate_gas_limit(parent, child, &spec.forks)?;
+    }
+    if options.check_blob_gas {
+        validate_blob_gas(parent, child, &spec.forks)?;
+    }
+    if options.check_difficulty {
+        validate_difficulty(parent, child, &spec.forks)?;
+    }
+    Ok(())
+}
@@ -1,3 +1,19 @@
+/// The activation blocks of the pre-Merge forks that changed the proof-of-work difficulty rules on mainnet.
+///
+/// Homestead and Byzantium changed how the difficulty adjusts to the block time, while Byzantium,
+/// Constantinople, Muir Glacier, London, Arrow Glacier and Gray Glacier each pushed back the exponential
+/// difficulty bomb.
+pub const HOMESTEAD_START: u64 = 1_150_000;
+pub const BYZANTIUM_START: u64 = 4_370_000;
+pub const CONSTANTINOPLE_START: u64 = 7_280_000;
+pub const MUIR_GLACIER_START: u64 = 9_200_000;
+pub const ARROW_GLACIER_START: u64 = 13_773_000;
+pub const GRAY_GLACIER_START: u64 = 15_050_000;
+
+/// The activation block of Berlin on mainnet, from which blocks may include access list transactions
+/// (type 1, EIP-2930).
+pub const BERLIN_START: u64 = 12_244_000;
+
 /// The last block number of the Genesis to London era.
 ///
 /// The Genesis to London era encompasses the initial phases of the Ethereum blockchain, starting from the Genesis block
@@ -78,11 +94,105 @@ pub const SHAPELLA_END_SEPOLIA: u64 = 5_187_061;
 pub const DENCUN_START: u64 = 19_426_587;
 pub const DENCUN_START_SEPOLIA: u64 = 5_187_062;
 
+/// The last block number of the Dencun era.
+///
+/// This constant marks the final block before the Pectra upgrade. Blocks up to and including this one
+/// use the 20-field Dencun header layout.
+///
+/// # Value
+/// - `22,431,083` is the block number marking the end of the Dencun era.
+pub const DENCUN_END: u64 = 22_431_083;
+
 /// The first block number of the Pectra era.
 ///
-/// The Pectra era begins at block `20,000,000` with the Pectra upgrade, which introduces key improvements to
-/// Ethereum's scalability, gas fee adjustments, and other protocol changes. This era continues the evolution of
-/// Ethereum after Dencun, with additional updates to the proof-of-stake consensus mechanism.
+/// The Pectra era begins at block `22,431,084` with the Pectra (Prague + Electra) upgrade, which introduces
+/// execution layer requests (EIP-7685) and appends the `requests_hash` field to the block header.
 ///
-// pub const PECTRA_START: u64 = 20_000_000;
+/// # Value
+/// - `22,431,084` is the block number where the Pectra upgrade begins.
+pub const PECTRA_START: u64 = 22_431_084;
 pub const PECTRA_START_SEPOLIA: u64 = 7_118_848;
+
+/// The block number of the Paris upgrade ("The Merge") on Sepolia.
+///
+/// Sepolia launched with London already active, so the Paris block is the first header layout change
+/// on that network that is still selected by block number.
+///
+/// # Value
+/// - `1,450,409` is the first proof-of-stake block on Sepolia.
+pub const PARIS_START_SEPOLIA: u64 = 1_450_409;
+
+/// The activation timestamp of the Shanghai upgrade.
+///
+/// Starting with Shanghai, execution layer forks activate at a fixed timestamp rather than at a block
+/// number. Any header whose `timestamp` is greater than or equal to this value uses the Shapella layout.
+///
+/// # Value
+/// - `1,681,338,455` is the Unix timestamp at which Shanghai activated on mainnet.
+pub const SHANGHAI_TIMESTAMP: u64 = 1_681_338_455;
+pub const SHANGHAI_TIMESTAMP_SEPOLIA: u64 = 1_677_557_088;
+
+/// The activation timestamp of the Cancun upgrade.
+///
+/// Headers at or after this timestamp use the Dencun layout, which adds the blob gas fields and the
+/// parent beacon block root.
+///
+/// # Value
+/// - `1,710,338,135` is the Unix timestamp at which Cancun activated on mainnet.
+pub const CANCUN_TIMESTAMP: u64 = 1_710_338_135;
+pub const CANCUN_TIMESTAMP_SEPOLIA: u64 = 1_706_655_072;
+
+/// The activation timestamp of the Prague upgrade.
+///
+/// Headers at or after this timestamp use the Pectra layout, which appends the `requests_hash` field.
+///
+/// # Value
+/// - `1,746,612,311` is the Unix timestamp at which Prague activated on mainnet.
+pub const PRAGUE_TIMESTAMP: u64 = 1_746_612_311;
+pub const PRAGUE_TIMESTAMP_SEPOLIA: u64 = 1_741_159_776;
+
+/// The activation timestamps of the Shanghai, Cancun and Prague upgrades on Holesky.
+///
+/// Holesky launched with every pre-Merge fork and the Merge itself active at genesis, so only the
+/// timestamp-activated forks need to be scheduled.
+pub const SHANGHAI_TIMESTAMP_HOLESKY: u64 = 1_696_000_704;
+pub const CANCUN_TIMESTAMP_HOLESKY: u64 = 1_707_305_664;
+pub const PRAGUE_TIMESTAMP_HOLESKY: u64 = 1_740_434_112;
+
+/// The activation timestamp of the Prague upgrade on Hoodi.
+///
+/// Hoodi launched with every fork up to and including Cancun active at genesis.
+pub const PRAGUE_TIMESTAMP_HOODI: u64 = 1_742_999_832;
+
+/// The terminal total difficulty of mainnet and Sepolia.
+///
+/// The last proof-of-work block is the first one whose total difficulty reaches this value; every block
+/// after it is proof-of-stake. Holesky and Hoodi were proof-of-stake from genesis, so their terminal total
+/// difficulty is zero.
+pub const TERMINAL_TOTAL_DIFFICULTY: u128 = 58_750_000_000_000_000_000_000;
+pub const TERMINAL_TOTAL_DIFFICULTY_SEPOLIA: u128 = 17_000_000_000_000_000;
+
+/// The genesis block hashes of the built-in chains.
+pub const GENESIS_HASH_MAINNET: &str =
+    "0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3";
+pub const GENESIS_HASH_SEPOLIA: &str =
+    "0x25a5cc106eea7138acab33231d7160d69cb777ee0c2c553fcddf5138993e6dd9";
+pub const GENESIS_HASH_HOLESKY: &str =
+    "0xb5f7f912443c940f21fd611f12828d75b534364ed9e95ca4e307729a4661bde4";
+pub const GENESIS_HASH_HOODI: &str =
+    "0xbbe312868b376a3001692a646dd2d7d1e4406380dfd86b98aa8a34d1557c971b";
+
+/// The Keccak256 hash of an empty RLP list, which is the `ommers_hash` of every block without ommers.
+///
+/// Since the Merge, blocks can no longer include ommers, so every proof-of-stake header carries this value.
+pub const EMPTY_OMMERS_HASH: &str =
+    "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347";
+
+/// The root of an empty Merkle Patricia trie: the Keccak256 hash of the RLP encoding of an empty string.
+///
+/// A block without transactions, receipts or withdrawals carries this value in the corresponding root.
+pub const EMPTY_ROOT_HASH: &str =
+    "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421";
+
+/// The largest `extra_data` a block header may carry, in bytes.
+pub const MAX_EXTRA_DATA_SIZE: usize = 32;
@@ -0,0 +1,176 @@
+use crate::eras;
+use crate::error::VerificationError;
+use eth_rlp_types::BlockHeader as VerifiableBlockHeader;
+use std::fmt;
+
+/// Describes where a sequence of block headers stops being a contiguous chain segment.
+///
+/// # Variants
+///
+/// - `Gap`: The header at `index` skips ahead, so the headers between `previous_number` and `number`
+///   are missing.
+/// - `OutOfOrder`: The header at `index` does not come after the header before it.
+/// - `TimestampNotIncreasing`: The header at `index` is not strictly newer than the header before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContinuityBreak {
+    Gap {
+        index: usize,
+        previous_number: u64,
+        number: u64,
+    },
+    OutOfOrder {
+        index: usize,
+        previous_number: u64,
+        number: u64,
+    },
+    TimestampNotIncreasing {
+        index: usize,
+        previous_timestamp: u64,
+        timestamp: u64,
+    },
+}
+
+impl ContinuityBreak {
+    /// Returns the index of the header at which continuity breaks.
+    pub fn index(&self) -> usize {
+        match self {
+            ContinuityBreak::Gap { index, .. }
+            | ContinuityBreak::OutOfOrder { index, .. }
+            | ContinuityBreak::TimestampNotIncreasing { index, .. } => *index,
+        }
+    }
+}
+
+impl fmt::Display for ContinuityBreak {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContinuityBreak::Gap {
+                index,
+                previous_number,
+                number,
+            } => write!(
+                f,
+                "gap at index {}: block {} follows block {}",
+                index, number, previous_number
+            ),
+            ContinuityBreak::OutOfOrder {
+                index,
+                previous_number,
+                number,
+            } => write!(
+                f,
+                "out of order at index {}: block {} follows block {}",
+                index, number, previous_number
+            ),
+            ContinuityBreak::TimestampNotIncreasing {
+                index,
+                previous_timestamp,
+                timestamp,
+            } => write!(
+                f,
+                "timestamp not increasing at index {}: {} follows {}",
+                index, timestamp, previous_timestamp
+            ),
+        }
+    }
+}
+
+/// Checks that `header`, found at `index`, directly follows `previous`: its number is exactly one
+/// higher and its timestamp strictly greater.
+///
+/// # Returns
+///
+/// `None` if the two headers are contiguous, the `ContinuityBreak` between them otherwise, or a
+/// `VerificationError` if a timestamp cannot be read.
+pub fn continuity_break(
+    previous: &VerifiableBlockHeader,
+    header: &VerifiableBlockHeader,
+    index: usize,
+) -> Result<Option<ContinuityBreak>, VerificationError> {
+    let previous_number = previous.number as u64;
+    let number = header.number as u64;
+    if number <= previous_number {
+        return Ok(Some(ContinuityBreak::OutOfOrder {
+            index,
+            previous_number,
+            number,
+        }));
+    }
+    if number != previous_number + 1 {
+        return Ok(Some(ContinuityBreak::Gap {
+            index,
+            previous_number,
+            number,
+        }));
+    }
+
+    let previous_timestamp = eras::header_timestamp(previous)?;
+    let timestamp = eras::header_timestamp(header)?;
+    if timestamp <= previous_timestamp {
+        return Ok(Some(ContinuityBreak::TimestampNotIncreasing {
+            index,
+            previous_timestamp,
+            timestamp,
+        }));
+    }
+
+    Ok(None)
+}
+
+/// Lists every place where a sequence of block headers is not a contiguous chain segment.
+///
+/// This only looks at block numbers and timestamps; it does not verify hashes or parent links.
+///
+/// # Returns
+///
+/// The continuity breaks in order of their index, empty if the headers are contiguous.
+pub fn find_continuity_breaks(
+    block_headers: &[VerifiableBlockHeader],
+) -> Result<Vec<ContinuityBreak>, VerificationError> {
+    let mut breaks = Vec::new();
+    for (i, pair) in block_headers.windows(2).enumerate() {
+        if let Some(found) = continuity_break(&pair[0], &pair[1], i + 1)? {
+            breaks.push(found);
+        }
+    }
+    Ok(breaks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::create_test_chain;
+
+    #[test]
+    fn test_find_continuity_breaks() {
+        let mut chain = create_test_chain(5);
+        chain[4].timestamp = chain[3].timestamp;
+        let mut headers: Vec<_> = chain
+            .iter()
+            .map(|header| VerifiableBlockHeader::try_from(header).unwrap())
+            .collect();
+        headers.remove(2);
+        headers.swap(0, 1);
+
+        assert_eq!(
+            find_continuity_breaks(&headers).unwrap(),
+            vec![
+                ContinuityBreak::OutOfOrder {
+                    index: 1,
+                    previous_number: 1_000_001,
+                    number: 1_000_000,
+                },
+                ContinuityBreak::Gap {
+                    index: 2,
+                    previous_number: 1_000_000,
+                    number: 1_000_003,
+                },
+                ContinuityBreak::TimestampNotIncreasing {
+                    index: 3,
+                    previous_timestamp: chain[3].timestamp,
+                    timestamp: chain[3].timestamp,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use crate::chain_spec::ChainSpec;
+use crate::eras;
+use crate::error::VerificationError;
+use crate::hash::keccak256;
+use eth_rlp_types::BlockHeader as VerifiableBlockHeader;
+use ethereum_types::H256;
+use rlp::Rlp;
+use std::fmt;
+
+/// The database columns holding the block header fields, in the order the fields appear in the RLP list.
+///
+/// Diffs name fields by column, like `VerificationError::MalformedField`, so a corrupt row can be fixed
+/// without translating from the RLP field names (e.g. `miner` rather than `beneficiary`).
+///
+/// Every era's layout is a prefix of this list: Genesis headers stop after `nonce`, London and Paris
+/// after `base_fee_per_gas`, Shapella after `withdrawals_root`, Dencun after `parent_beacon_block_root`.
+pub const HEADER_FIELDS: [&str; 21] = [
+    "parent_hash",
+    "sha3_uncles",
+    "miner",
+    "state_root",
+    "transaction_root",
+    "receipts_root",
+    "logs_bloom",
+    "difficulty",
+    "number",
+    "gas_limit",
+    "gas_used",
+    "timestamp",
+    "extra_data",
+    "mix_hash",
+    "nonce",
+    "base_fee_per_gas",
+    "withdrawals_root",
+    "blob_gas_used",
+    "excess_blob_gas",
+    "parent_beacon_block_root",
+    "request_hash",
+];
+
+/// A single header field whose RLP encoding differs between the local and the trusted header.
+///
+/// # Fields
+///
+/// - `index`: The position of the field in the RLP list.
+/// - `field`: The database column of the field, taken from [`HEADER_FIELDS`].
+/// - `local`: The RLP encoding of the field in the local header, or `None` if its layout has no such field.
+/// - `trusted`: The RLP encoding of the field in the trusted header, or `None` if its layout has no such field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    pub index: usize,
+    pub field: &'static str,
+    pub local: Option<Vec<u8>>,
+    pub trusted: Option<Vec<u8>>,
+}
+
+/// The result of comparing a local block header with a trusted one, field by field.
+///
+/// # Fields
+///
+/// - `local_hash`: The hash computed from the local header.
+/// - `trusted_hash`: The hash of the trusted header.
+/// - `fields`: Every field whose encoding differs, in RLP list order. Empty when the headers match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderDiff {
+    pub local_hash: H256,
+    pub trusted_hash: H256,
+    pub fields: Vec<FieldDiff>,
+}
+
+impl HeaderDiff {
+    /// Returns `true` if the local header encodes exactly like the trusted one.
+    pub fn is_match(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+impl fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let encoding = |item: &Option<Vec<u8>>| match item {
+            Some(bytes) => format!("0x{}", hex::encode(bytes)),
+            None => "<absent>".to_string(),
+        };
+        write!(
+            f,
+            "{} (item {}): local {}, trusted {}",
+            self.field,
+            self.index,
+            encoding(&self.local),
+            encoding(&self.trusted)
+        )
+    }
+}
+
+impl fmt::Display for HeaderDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "local hash {:?}, trusted hash {:?}",
+            self.local_hash, self.trusted_hash
+        )?;
+        for field in &self.fields {
+            write!(f, "\n  {}", field)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares a database block header with a trusted raw RLP-encoded header.
+///
+/// The local header is encoded with the layout of the era it belongs to under `spec`, and both
+/// encodings are then compared item by item. This pinpoints which column of a stored header is
+/// corrupt when its hash does not match.
+///
+/// # Arguments
+///
+/// - `header`: The block header to check, usually read from the database.
+/// - `trusted_rlp`: The RLP encoding of the same header obtained from a trusted source, such as a node.
+/// - `spec`: The `ChainSpec` used to pick the layout of the local header.
+///
+/// # Returns
+///
+/// A `HeaderDiff` listing the fields that differ, or a `VerificationError` if the local header cannot be
+/// encoded or `trusted_rlp` is not an RLP list.
+pub fn diff_header_against_rlp(
+    header: &VerifiableBlockHeader,
+    trusted_rlp: &[u8],
+    spec: &ChainSpec,
+) -> Result<HeaderDiff, VerificationError> {
+    let local_rlp = encode(header, spec)?;
+    let local = list_items(&local_rlp).map_err(|e| VerificationError::malformed("header", e))?;
+    let trusted =
+        list_items(trusted_rlp).map_err(|e| VerificationError::malformed("trusted_rlp", e))?;
+
+    let fields = (0..local.len().max(trusted.len()))
+        .filter(|&i| local.get(i) != trusted.get(i))
+        .map(|i| FieldDiff {
+            index: i,
+            field: HEADER_FIELDS.get(i).copied().unwrap_or("unknown"),
+            local: local.get(i).cloned(),
+            trusted: trusted.get(i).cloned(),
+        })
+        .collect();
+
+    Ok(HeaderDiff {
+        local_hash: keccak256(&local_rlp),
+        trusted_hash: keccak256(trusted_rlp),
+        fields,
+    })
+}
+
+/// Compares a database block header with a trusted alternate copy of the same header.
+///
+/// Both headers are encoded with the layout of the era they belong to under `spec` and compared with
+/// [`diff_header_against_rlp`].
+pub fn diff_headers(
+    header: &VerifiableBlockHeader,
+    trusted: &VerifiableBlockHeader,
+    spec: &ChainSpec,
+) -> Result<HeaderDiff, VerificationError> {
+    let trusted_rlp = encode(trusted, spec)?;
+    diff_header_against_rlp(header, &trusted_rlp, spec)
+}
+
+fn encode(header: &VerifiableBlockHeader, spec: &ChainSpec) -> Result<Vec<u8>, VerificationError> {
+    let timestamp = eras::header_timestamp(header)?;
+    let encoder = eras::determine_era_encoder(header.number as u64, timestamp, &spec.forks);
+    encoder(header)
+}
+
+fn list_items(data: &[u8]) -> Result<Vec<Vec<u8>>, rlp::DecoderError> {
+    let rlp = Rlp::new(data);
+    if !rlp.is_list() {
+        return Err(rlp::DecoderError::RlpExpectedToBeList);
+    }
+    rlp.item_count()?;
+    Ok(rlp.iter().map(|item| item.as_raw().to_vec()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::create_test_block_header_pectra_hoodi;
+
+    #[test]
+    fn test_diff_reports_corrupt_field() {
+        let spec = ChainSpec::hoodi();
+        let trusted = create_test_block_header_pectra_hoodi();
+        let mut corrupt = trusted.clone();
+        corrupt.gas_used += 1;
+
+        let diff = diff_headers(&corrupt, &trusted, &spec).unwrap();
+        assert!(!diff.is_match());
+        assert_eq!(diff.fields.len(), 1);
+        assert_eq!(diff.fields[0].field, "gas_used");
+        assert_eq!(diff.fields[0].index, 10);
+        assert_eq!(
+            diff.trusted_hash,
+            trusted.block_hash.parse::<H256>().unwrap()
+        );
+
+        let mut corrupt = trusted.clone();
+        corrupt.miner = Some(format!("{:?}", ethereum_types::H160::repeat_byte(0x01)));
+        let diff = diff_headers(&corrupt, &trusted, &spec).unwrap();
+        assert_eq!(diff.fields[0].field, "miner");
+
+        let trusted_rlp = encode(&trusted, &spec).unwrap();
+        let diff = diff_header_against_rlp(&trusted, &trusted_rlp, &spec).unwrap();
+        assert!(diff.is_match());
+        assert_eq!(diff.local_hash, diff.trusted_hash);
+    }
+}
@@ -0,0 +1,312 @@
+use super::{
+    BlockHeaderDencun, BlockHeaderGenesis, BlockHeaderLondon, BlockHeaderParis, BlockHeaderPectra,
+    BlockHeaderShapella, Era, ForkSchedule,
+};
+use crate::bloom::LogsBloom;
+use crate::error::VerificationError;
+use eth_rlp_types::{BlockHeader as VerifiableBlockHeader, BlockHeaderTrait};
+use ethereum_types::{H256, U256};
+use rlp::Rlp;
+
+/// A block header decoded into the struct of the era whose layout it uses.
+///
+/// Returned by [`decode_any_header`] when the era of a raw header is not known in advance.
+#[derive(Debug, PartialEq)]
+pub enum EraHeader {
+    Genesis(BlockHeaderGenesis),
+    London(BlockHeaderLondon),
+    Paris(BlockHeaderParis),
+    Shapella(BlockHeaderShapella),
+    Dencun(BlockHeaderDencun),
+    Pectra(BlockHeaderPectra),
+}
+
+impl EraHeader {
+    /// Parses a database block header into the struct of the era `forks` assigns to it.
+    ///
+    /// # Returns
+    ///
+    /// The era header, or a `VerificationError` if the header has no usable timestamp or one of the
+    /// fields its era requires is missing or malformed.
+    pub fn from_header(
+        header: &VerifiableBlockHeader,
+        forks: &ForkSchedule,
+    ) -> Result<Self, VerificationError> {
+        let timestamp = super::header_timestamp(header)?;
+        Ok(match forks.era_at(header.number as u64, timestamp) {
+            Era::Genesis => EraHeader::Genesis(BlockHeaderGenesis::try_from(header)?),
+            Era::London => EraHeader::London(BlockHeaderLondon::try_from(header)?),
+            Era::Paris => EraHeader::Paris(BlockHeaderParis::try_from(header)?),
+            Era::Shapella => EraHeader::Shapella(BlockHeaderShapella::try_from(header)?),
+            Era::Dencun => EraHeader::Dencun(BlockHeaderDencun::try_from(header)?),
+            Era::Pectra => EraHeader::Pectra(BlockHeaderPectra::try_from(header)?),
+        })
+    }
+
+    /// Returns the era whose header layout this header uses.
+    pub fn era(&self) -> Era {
+        match self {
+            EraHeader::Genesis(_) => Era::Genesis,
+            EraHeader::London(_) => Era::London,
+            EraHeader::Paris(_) => Era::Paris,
+            EraHeader::Shapella(_) => Era::Shapella,
+            EraHeader::Dencun(_) => Era::Dencun,
+            EraHeader::Pectra(_) => Era::Pectra,
+        }
+    }
+
+    /// Returns the root of the trie of the block's transactions.
+    pub fn transactions_root(&self) -> H256 {
+        match self {
+            EraHeader::Genesis(header) => header.transactions_root,
+            EraHeader::London(header) => header.transactions_root,
+            EraHeader::Paris(header) => header.transactions_root,
+            EraHeader::Shapella(header) => header.transactions_root,
+            EraHeader::Dencun(header) => header.transactions_root,
+            EraHeader::Pectra(header) => header.transactions_root,
+        }
+    }
+
+    /// Returns the root of the trie of the block's receipts.
+    pub fn receipts_root(&self) -> H256 {
+        match self {
+            EraHeader::Genesis(header) => header.receipts_root,
+            EraHeader::London(header) => header.receipts_root,
+            EraHeader::Paris(header) => header.receipts_root,
+            EraHeader::Shapella(header) => header.receipts_root,
+            EraHeader::Dencun(header) => header.receipts_root,
+            EraHeader::Pectra(header) => header.receipts_root,
+        }
+    }
+
+    /// Returns the bloom filter of every log emitted in the block.
+    pub fn logs_bloom(&self) -> LogsBloom {
+        LogsBloom(match self {
+            EraHeader::Genesis(header) => header.logs_bloom,
+            EraHeader::London(header) => header.logs_bloom,
+            EraHeader::Paris(header) => header.logs_bloom,
+            EraHeader::Shapella(header) => header.logs_bloom,
+            EraHeader::Dencun(header) => header.logs_bloom,
+            EraHeader::Pectra(header) => header.logs_bloom,
+        })
+    }
+
+    /// Returns the root of the trie of the block's withdrawals, or `None` before Shapella.
+    pub fn withdrawals_root(&self) -> Option<H256> {
+        match self {
+            EraHeader::Genesis(_) | EraHeader::London(_) | EraHeader::Paris(_) => None,
+            EraHeader::Shapella(header) => Some(header.withdrawals_root),
+            EraHeader::Dencun(header) => Some(header.withdrawals_root),
+            EraHeader::Pectra(header) => Some(header.withdrawals_root),
+        }
+    }
+
+    /// Returns the EIP-7685 commitment to the block's execution layer requests, or `None` before Pectra.
+    pub fn requests_hash(&self) -> Option<H256> {
+        match self {
+            EraHeader::Pectra(header) => Some(header.request_hash),
+            _ => None,
+        }
+    }
+
+    /// RLP encodes the header with the layout of its era.
+    pub fn rlp_encode(&self) -> Vec<u8> {
+        match self {
+            EraHeader::Genesis(header) => header.rlp_encode(),
+            EraHeader::London(header) => header.rlp_encode(),
+            EraHeader::Paris(header) => header.rlp_encode(),
+            EraHeader::Shapella(header) => header.rlp_encode(),
+            EraHeader::Dencun(header) => header.rlp_encode(),
+            EraHeader::Pectra(header) => header.rlp_encode(),
+        }
+    }
+
+    /// Computes the Keccak256 hash of the header, i.e. its block hash.
+    pub fn compute_hash(&self) -> H256 {
+        match self {
+            EraHeader::Genesis(header) => header.compute_hash(),
+            EraHeader::London(header) => header.compute_hash(),
+            EraHeader::Paris(header) => header.compute_hash(),
+            EraHeader::Shapella(header) => header.compute_hash(),
+            EraHeader::Dencun(header) => header.compute_hash(),
+            EraHeader::Pectra(header) => header.compute_hash(),
+        }
+    }
+
+    /// Converts the header into the common `BlockHeader` used throughout the application.
+    ///
+    /// # Returns
+    ///
+    /// The database header, or `VerificationError::MalformedField` if a quantity does not fit in the
+    /// signed 64-bit column that stores it.
+    pub fn into_verifiable(self) -> Result<VerifiableBlockHeader, VerificationError> {
+        match self {
+            EraHeader::Genesis(header) => header.into_verifiable(),
+            EraHeader::London(header) => header.into_verifiable(),
+            EraHeader::Paris(header) => header.into_verifiable(),
+            EraHeader::Shapella(header) => header.into_verifiable(),
+            EraHeader::Dencun(header) => header.into_verifiable(),
+            EraHeader::Pectra(header) => header.into_verifiable(),
+        }
+    }
+}
+
+/// Decodes an RLP-encoded block header of any era without knowing its block number or chain.
+///
+/// Each fork that changed the header layout appended fields to it, so the length of the RLP list
+/// identifies the variant: 15 items for Genesis, 16 for London and Paris, 17 for Shapella, 20 for
+/// Dencun and 21 for Pectra. London and Paris share a layout and are told apart by the difficulty,
+/// which is zero for every proof-of-stake block.
+///
+/// # Arguments
+///
+/// - `data`: A byte slice containing the RLP-encoded block header, e.g. as received from a peer.
+///
+/// # Returns
+///
+/// The decoded header together with its recomputed hash, or `VerificationError::MalformedField` if
+/// `data` is not an RLP list of a known header length or one of its items cannot be decoded.
+pub fn decode_any_header(data: &[u8]) -> Result<(EraHeader, H256), VerificationError> {
+    let rlp = Rlp::new(data);
+    if !rlp.is_list() {
+        return Err(VerificationError::malformed(
+            "rlp",
+            "block header is not an RLP list",
+        ));
+    }
+    let item_count = rlp
+        .item_count()
+        .map_err(|e| VerificationError::malformed("rlp", e))?;
+
+    let header = match item_count {
+        15 => BlockHeaderGenesis::rlp_decode(data).map(EraHeader::Genesis),
+        16 => {
+            let difficulty: U256 = rlp
+                .val_at(7)
+                .map_err(|e| VerificationError::malformed("difficulty", e))?;
+            if difficulty.is_zero() {
+                BlockHeaderParis::rlp_decode(data).map(EraHeader::Paris)
+            } else {
+                BlockHeaderLondon::rlp_decode(data).map(EraHeader::London)
+            }
+        }
+        17 => BlockHeaderShapella::rlp_decode(data).map(EraHeader::Shapella),
+        20 => BlockHeaderDencun::rlp_decode(data).map(EraHeader::Dencun),
+        21 => BlockHeaderPectra::rlp_decode(data).map(EraHeader::Pectra),
+        other => {
+            return Err(VerificationError::malformed(
+                "rlp",
+                format!("no header layout has {} fields", other),
+            ))
+        }
+    }
+    .map_err(|e| VerificationError::malformed("rlp", e))?;
+
+    let hash = header.compute_hash();
+    Ok((header, hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain_spec::ChainSpec;
+    use crate::test_helpers::{
+        create_test_block_header_cancun, create_test_block_header_london,
+        create_test_block_header_paris, create_test_block_header_pectra_hoodi,
+        create_test_block_header_pectra_sepolia, create_test_block_header_shapella, header_columns,
+    };
+    use std::str::FromStr;
+
+    #[test]
+    fn test_into_verifiable_round_trip() {
+        // No pre-London header is among the fixtures, so the Genesis row is the London block without
+        // its base fee, re-dated one block before London with the hash of that layout.
+        let mut frontier = create_test_block_header_london();
+        frontier.number = 12_964_999;
+        frontier.base_fee_per_gas = None;
+        frontier.block_hash = format!(
+            "{:?}",
+            BlockHeaderGenesis::try_from(&frontier)
+                .unwrap()
+                .compute_hash()
+        );
+
+        for (db_header, spec, era) in [
+            (frontier, ChainSpec::mainnet(), Era::Genesis),
+            (
+                create_test_block_header_london(),
+                ChainSpec::mainnet(),
+                Era::London,
+            ),
+            (
+                create_test_block_header_paris(),
+                ChainSpec::mainnet(),
+                Era::Paris,
+            ),
+            (
+                create_test_block_header_shapella(),
+                ChainSpec::mainnet(),
+                Era::Shapella,
+            ),
+            (
+                create_test_block_header_cancun(),
+                ChainSpec::mainnet(),
+                Era::Dencun,
+            ),
+            (
+                create_test_block_header_pectra_sepolia(),
+                ChainSpec::sepolia(),
+                Era::Pectra,
+            ),
+            (
+                create_test_block_header_pectra_hoodi(),
+                ChainSpec::hoodi(),
+                Era::Pectra,
+            ),
+        ] {
+            let header = EraHeader::from_header(&db_header, &spec.forks).unwrap();
+            assert_eq!(header.era(), era);
+            let hash = header.compute_hash();
+
+            let verifiable = header.into_verifiable().unwrap();
+            assert_eq!(
+                EraHeader::from_header(&verifiable, &spec.forks).unwrap(),
+                EraHeader::from_header(&db_header, &spec.forks).unwrap()
+            );
+            // The Paris, Shapella and Dencun fixtures carry placeholder block hashes, so the converted
+            // header is expected to hold the recomputed one.
+            assert_eq!(
+                verifiable,
+                VerifiableBlockHeader {
+                    block_hash: format!("{:?}", hash),
+                    ..header_columns(&db_header)
+                },
+                "{:?}",
+                era
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_any_header() {
+        let db_header = create_test_block_header_pectra_hoodi();
+        let encoded = BlockHeaderPectra::try_from(&db_header)
+            .unwrap()
+            .rlp_encode();
+
+        let (header, hash) = decode_any_header(&encoded).unwrap();
+        assert_eq!(header.era(), Era::Pectra);
+        assert_eq!(hash, H256::from_str(&db_header.block_hash).unwrap());
+
+        let encoded = BlockHeaderLondon::try_from(&create_test_block_header_london())
+            .unwrap()
+            .rlp_encode();
+        let (header, _) = decode_any_header(&encoded).unwrap();
+        assert_eq!(header.era(), Era::London);
+
+        assert!(matches!(
+            decode_any_header(&encoded[..encoded.len() - 1]),
+            Err(VerificationError::MalformedField { field: "rlp", .. })
+        ));
+    }
+}
@@ -1,3 +1,5 @@
+use super::fields;
+use crate::error::VerificationError;
 use eth_rlp_types::{BlockHeader as VerifiableBlockHeader, BlockHeaderTrait}; // Alias for clarity
 use ethereum_types::{H160, H256, U256};
 use eyre::Result;
@@ -57,92 +59,98 @@ pub struct BlockHeaderDencun {
 }
 
 impl BlockHeaderDencun {
-    /// Converts a `VerifiableBlockHeader` from the database into a `BlockHeaderDencun`.
+    /// Converts a `VerifiableBlockHeader` fetched from the database into a `BlockHeaderDencun`.
     ///
-    /// This method takes a block header from the database, parses its fields, and converts it
-    /// into a `BlockHeaderDencun` struct. It handles conversion of fields such as `logs_bloom`,
-    /// `nonce`, and various cryptographic root fields, ensuring that the header is ready for verification.
+    /// # Panics
     ///
-    /// # Arguments
+    /// Panics if a field is missing or malformed. `BlockHeaderDencun::try_from(&db_header)`
+    /// reports the field as a `VerificationError` instead.
+    #[deprecated(
+        note = "use `BlockHeaderDencun::try_from(&db_header)`, which reports malformed fields"
+    )]
+    pub fn from_db_header(db_header: VerifiableBlockHeader) -> Self {
+        Self::try_from(&db_header).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Converts a `BlockHeaderDencun` into a common `VerifiableBlockHeader`.
     ///
-    /// - `db_header`: A `VerifiableBlockHeader` fetched from the database, containing the raw string data
-    ///   for the block header fields.
+    /// The result uses the same formats the database does (`0x`-prefixed hex for hashes, byte strings
+    /// and quantities), carries the computed block hash, and fills both `ommers_hash` and `sha3_uncles`,
+    /// so converting it back with `BlockHeaderDencun::try_from` yields the same header.
     ///
     /// # Returns
     ///
-    /// A `BlockHeaderDencun` instance populated with the parsed and validated block header data.
-    pub fn from_db_header(db_header: VerifiableBlockHeader) -> Self {
-        let logs_bloom = <Self as BlockHeaderTrait>::hex_to_fixed_array::<256>(
-            &db_header.logs_bloom.unwrap_or_default(),
-        );
-        let nonce = <Self as BlockHeaderTrait>::hex_to_fixed_array::<8>("0x0000000000000000");
-
-        BlockHeaderDencun {
-            parent_hash: H256::from_str(&db_header.parent_hash.unwrap_or_default()).unwrap(),
-            ommers_hash: H256::from_str(&db_header.sha3_uncles.unwrap_or_default()).unwrap(),
-            beneficiary: H160::from_str(&db_header.miner.unwrap_or_default()).unwrap(),
-            state_root: H256::from_str(&db_header.state_root.unwrap_or_default()).unwrap(),
-            transactions_root: H256::from_str(&db_header.transaction_root.unwrap_or_default())
-                .unwrap(),
-            receipts_root: H256::from_str(&db_header.receipts_root.unwrap_or_default()).unwrap(),
-            logs_bloom,
-            difficulty: U256::from_str(&db_header.difficulty.unwrap_or_default()).unwrap(),
-            number: U256::from(db_header.number as u64),
-            gas_limit: U256::from(db_header.gas_limit as u64),
-            gas_used: U256::from(db_header.gas_used as u64),
-            timestamp: U256::from_str(&db_header.timestamp.unwrap_or_default()).unwrap(),
-            extra_data: hex::decode(&db_header.extra_data.unwrap_or_default()[2..])
-                .unwrap_or_default(),
-            mix_hash: H256::from_str(&db_header.mix_hash.unwrap_or_default()).unwrap(),
-            nonce,
-            base_fee_per_gas: U256::from_str(&db_header.base_fee_per_gas.unwrap_or_default())
-                .unwrap(),
-            withdrawals_root: H256::from_str(&db_header.withdrawals_root.unwrap_or_default())
-                .unwrap_or_default(),
-            parent_beacon_block_root: H256::from_str(
-                &db_header.parent_beacon_block_root.unwrap_or_default(),
-            )
-            .unwrap_or_default(),
-            blob_gas_used: U256::from_str(&db_header.blob_gas_used.unwrap_or_default())
-                .unwrap_or_default(),
-            excess_blob_gas: U256::from_str(
-                &db_header.excess_blob_gas.unwrap_or("0x0".to_string()),
-            )
-            .unwrap_or_default(),
-        }
+    /// The database header, or `VerificationError::MalformedField` if `number`, `gas_limit` or
+    /// `gas_used` does not fit in the signed 64-bit columns that store them.
+    pub fn into_verifiable(self) -> Result<VerifiableBlockHeader, VerificationError> {
+        let ommers_hash = format!("{:?}", self.ommers_hash);
+        Ok(VerifiableBlockHeader {
+            block_hash: format!("{:?}", self.compute_hash()),
+            parent_hash: Some(format!("{:?}", self.parent_hash)),
+            ommers_hash: Some(ommers_hash.clone()),
+            miner: Some(format!("{:?}", self.beneficiary)),
+            state_root: Some(format!("{:?}", self.state_root)),
+            transaction_root: Some(format!("{:?}", self.transactions_root)),
+            receipts_root: Some(format!("{:?}", self.receipts_root)),
+            logs_bloom: Some(format!("0x{}", hex::encode(self.logs_bloom))),
+            difficulty: Some(format!("{:#x}", self.difficulty)),
+            totaldifficulty: None, // Not part of the header.
+            number: fields::to_integer("number", self.number)?,
+            gas_limit: fields::to_integer("gas_limit", self.gas_limit)?,
+            gas_used: fields::to_integer("gas_used", self.gas_used)?,
+            timestamp: Some(format!("{:#x}", self.timestamp)),
+            extra_data: Some(format!("0x{}", hex::encode(&self.extra_data))),
+            mix_hash: Some(format!("{:?}", self.mix_hash)),
+            nonce: format!("0x{}", hex::encode(self.nonce)),
+            base_fee_per_gas: Some(format!("{:#x}", self.base_fee_per_gas)),
+            withdrawals_root: Some(format!("{:?}", self.withdrawals_root)),
+            blob_gas_used: Some(format!("{:#x}", self.blob_gas_used)),
+            excess_blob_gas: Some(format!("{:#x}", self.excess_blob_gas)),
+            parent_beacon_block_root: Some(format!("{:?}", self.parent_beacon_block_root)),
+            sha3_uncles: Some(ommers_hash),
+            request_hash: None,
+        })
     }
+}
+
+impl TryFrom<&VerifiableBlockHeader> for BlockHeaderDencun {
+    type Error = VerificationError;
 
-    /// Converts a `BlockHeaderDencun` into a common `BlockHeader`.
+    /// Parses a database block header, as stored by the indexer, into a `BlockHeaderDencun`.
     ///
-    /// This method ensures that the Dencun-specific block header structure is converted into the
-    /// generic `BlockHeader` structure used throughout the application.
-    pub fn into_verifiable(self) -> VerifiableBlockHeader {
-        VerifiableBlockHeader {
-            block_hash: "".to_string(), // Placeholder; compute if necessary.
-            parent_hash: Some(self.parent_hash.to_string()),
-            ommers_hash: Some(self.ommers_hash.to_string()),
-            miner: Some(self.beneficiary.to_string()),
-            state_root: Some(self.state_root.to_string()),
-            transaction_root: Some(self.transactions_root.to_string()),
-            receipts_root: Some(self.receipts_root.to_string()),
-            logs_bloom: Some(hex::encode(self.logs_bloom)),
-            difficulty: Some(self.difficulty.to_string()),
-            totaldifficulty: None, // Not available in Dencun.
-            number: self.number.as_u64() as i64,
-            gas_limit: self.gas_limit.as_u64() as i64,
-            gas_used: self.gas_used.as_u64() as i64,
-            timestamp: Some(self.timestamp.to_string()),
-            extra_data: Some(hex::encode(self.extra_data)),
-            mix_hash: Some(self.mix_hash.to_string()),
-            nonce: hex::encode(self.nonce),
-            base_fee_per_gas: Some(self.base_fee_per_gas.to_string()),
-            withdrawals_root: Some(self.withdrawals_root.to_string()),
-            parent_beacon_block_root: Some(self.parent_beacon_block_root.to_string()),
-            blob_gas_used: Some(self.blob_gas_used.to_string()),
-            excess_blob_gas: Some(self.excess_blob_gas.to_string()),
-            sha3_uncles: None, // Not applicable for Dencun.
-            request_hash: None,
-        }
+    /// # Returns
+    ///
+    /// The parsed header, or `VerificationError::MalformedField` naming the first column that is
+    /// missing or cannot be parsed.
+    fn try_from(db_header: &VerifiableBlockHeader) -> Result<Self, Self::Error> {
+        Ok(BlockHeaderDencun {
+            parent_hash: fields::h256("parent_hash", &db_header.parent_hash)?,
+            ommers_hash: fields::h256("sha3_uncles", &db_header.sha3_uncles)?,
+            beneficiary: fields::h160("miner", &db_header.miner)?,
+            state_root: fields::h256("state_root", &db_header.state_root)?,
+            transactions_root: fields::h256("transaction_root", &db_header.transaction_root)?,
+            receipts_root: fields::h256("receipts_root", &db_header.receipts_root)?,
+            logs_bloom: fields::fixed_bytes::<256>(
+                "logs_bloom",
+                db_header.logs_bloom.as_deref().unwrap_or_default(),
+            )?,
+            difficulty: fields::u256_or_zero("difficulty", &db_header.difficulty)?,
+            number: fields::quantity("number", db_header.number)?,
+            gas_limit: fields::quantity("gas_limit", db_header.gas_limit)?,
+            gas_used: fields::quantity("gas_used", db_header.gas_used)?,
+            timestamp: fields::u256("timestamp", &db_header.timestamp)?,
+            extra_data: fields::bytes("extra_data", &db_header.extra_data)?,
+            mix_hash: fields::h256("mix_hash", &db_header.mix_hash)?,
+            nonce: fields::fixed_bytes::<8>("nonce", &db_header.nonce)?,
+            base_fee_per_gas: fields::u256("base_fee_per_gas", &db_header.base_fee_per_gas)?,
+            withdrawals_root: fields::h256("withdrawals_root", &db_header.withdrawals_root)?,
+            parent_beacon_block_root: fields::h256(
+                "parent_beacon_block_root",
+                &db_header.parent_beacon_block_root,
+            )?,
+            blob_gas_used: fields::u256("blob_gas_used", &db_header.blob_gas_used)?,
+            excess_blob_gas: fields::u256("excess_blob_gas", &db_header.excess_blob_gas)?,
+        })
     }
 }
 
@@ -240,27 +248,35 @@ impl BlockHeaderTrait for BlockHeaderDencun {
 ///
 /// # Returns
 ///
-/// A boolean indicating whether the computed block hash matches the provided block hash.
-pub fn verify_hash_dencun(block_hash: String, db_header: VerifiableBlockHeader) -> bool {
-    // Store the block number before moving db_header
+/// `Ok(())` if the computed block hash matches the provided block hash, or a `VerificationError`
+/// describing the malformed field or the hash mismatch otherwise.
+pub fn verify_hash_dencun(
+    block_hash: String,
+    db_header: VerifiableBlockHeader,
+) -> Result<(), VerificationError> {
+    // Store the block number for error reporting
     let block_number = db_header.number;
-    let header = BlockHeaderDencun::from_db_header(db_header.clone());
+    let header = BlockHeaderDencun::try_from(&db_header)?;
 
     // Compute the block hash
     let computed_block_hash = header.compute_hash();
-    let expected_hash = H256::from_str(&block_hash).unwrap();
+    let expected_hash =
+        H256::from_str(&block_hash).map_err(|e| VerificationError::malformed("block_hash", e))?;
 
     // Check if the computed hash matches the given block hash
-    let matches = computed_block_hash == expected_hash;
-
-    if !matches {
+    if computed_block_hash != expected_hash {
         error!(
             "Hash verification failed for Dencun block: expected {}, computed {}, block number {}",
             expected_hash, computed_block_hash, block_number
         );
+        return Err(VerificationError::HashMismatch {
+            block_number: block_number as u64,
+            expected: expected_hash,
+            computed: computed_block_hash,
+        });
     }
 
-    matches
+    Ok(())
 }
 
 #[cfg(test)]
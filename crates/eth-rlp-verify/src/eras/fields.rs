@@ -0,0 +1,24 @@
+use eth_rlp_types::fields::{self, FieldError};
+use ethereum_types::U256;
+
+// Column parsers used by the `TryFrom<&BlockHeader>` conversions of every era.
+//
+// They are the parsers of `eth_rlp_types::fields`, which `ExecutionHeader` uses as well, so a row is
+// accepted or rejected the same way by every typed view of it. A bad column is reported as a
+// `FieldError` naming it, which converts into `VerificationError::MalformedField`.
+pub(crate) use eth_rlp_types::fields::{bytes, fixed_bytes, h160, h256, u256, u256_or_zero};
+
+/// Converts a `bigint` column into a quantity, rejecting negative values.
+pub(crate) fn quantity(field: &'static str, value: i64) -> Result<U256, FieldError> {
+    fields::integer(field, value).map(U256::from)
+}
+
+/// Converts a quantity into a `bigint` column, rejecting values above `i64::MAX`.
+pub(crate) fn to_integer(field: &'static str, value: U256) -> Result<i64, FieldError> {
+    i64::try_from(value).map_err(|_| {
+        FieldError::new(
+            field,
+            format!("value {} does not fit in a signed 64-bit column", value),
+        )
+    })
+}
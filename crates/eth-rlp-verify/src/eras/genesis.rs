@@ -1,3 +1,5 @@
+use super::fields;
+use crate::error::VerificationError;
 use eth_rlp_types::{BlockHeader as VerifiableBlockHeader, BlockHeaderTrait}; // Alias for clarity
 use ethereum_types::{H160, H256, U256};
 use eyre::Result;
@@ -51,70 +53,88 @@ pub struct BlockHeaderGenesis {
 impl BlockHeaderGenesis {
     /// Converts a `VerifiableBlockHeader` fetched from the database into a `BlockHeaderGenesis`.
     ///
-    /// This method takes a database block header and converts it into the appropriate `BlockHeaderGenesis` structure
-    /// by parsing and populating all necessary fields such as `parent_hash`, `ommers_hash`, and `logs_bloom`.
+    /// # Panics
     ///
-    /// # Arguments
+    /// Panics if a field is missing or malformed. `BlockHeaderGenesis::try_from(&db_header)`
+    /// reports the field as a `VerificationError` instead.
+    #[deprecated(
+        note = "use `BlockHeaderGenesis::try_from(&db_header)`, which reports malformed fields"
+    )]
+    pub fn from_db_header(db_header: VerifiableBlockHeader) -> Self {
+        Self::try_from(&db_header).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Converts a `BlockHeaderGenesis` into a common `VerifiableBlockHeader`.
     ///
-    /// - `db_header`: A `VerifiableBlockHeader` fetched from the database, containing various fields in string format.
+    /// The result uses the same formats the database does (`0x`-prefixed hex for hashes, byte strings
+    /// and quantities), carries the computed block hash, and fills both `ommers_hash` and `sha3_uncles`,
+    /// so converting it back with `BlockHeaderGenesis::try_from` yields the same header.
     ///
     /// # Returns
     ///
-    /// A `BlockHeaderGenesis` instance containing the parsed and validated block header data.
-    pub fn from_db_header(db_header: VerifiableBlockHeader) -> Self {
-        let logs_bloom = <Self as BlockHeaderTrait>::hex_to_fixed_array::<256>(
-            &db_header.logs_bloom.clone().unwrap_or_default(),
-        );
-        let nonce = <Self as BlockHeaderTrait>::hex_to_fixed_array::<8>(&db_header.nonce.clone());
-
-        BlockHeaderGenesis {
-            parent_hash: H256::from_str(&db_header.parent_hash.unwrap_or_default()).unwrap(),
-            ommers_hash: H256::from_str(&db_header.sha3_uncles.unwrap_or_default()).unwrap(),
-            beneficiary: H160::from_str(&db_header.miner.unwrap_or_default()).unwrap(),
-            state_root: H256::from_str(&db_header.state_root.unwrap_or_default()).unwrap(),
-            transactions_root: H256::from_str(&db_header.transaction_root.unwrap_or_default())
-                .unwrap(),
-            receipts_root: H256::from_str(&db_header.receipts_root.unwrap_or_default()).unwrap(),
-            logs_bloom,
-            difficulty: U256::from_str(&db_header.difficulty.unwrap_or("0x0".to_string())).unwrap(),
-            number: U256::from(db_header.number as u64),
-            gas_limit: U256::from(db_header.gas_limit as u64),
-            gas_used: U256::from(db_header.gas_used as u64),
-            timestamp: U256::from_str(&db_header.timestamp.unwrap_or_default()).unwrap(),
-            extra_data: hex::decode(&db_header.extra_data.unwrap_or_default()[2..])
-                .unwrap_or_default(),
-            mix_hash: H256::from_str(&db_header.mix_hash.unwrap_or_default()).unwrap(),
-            nonce,
-        }
-    }
-
-    pub fn into_verifiable(self) -> VerifiableBlockHeader {
-        VerifiableBlockHeader {
-            block_hash: "".to_string(),
-            parent_hash: Some(self.parent_hash.to_string()),
-            ommers_hash: Some(self.ommers_hash.to_string()),
-            miner: Some(self.beneficiary.to_string()),
-            state_root: Some(self.state_root.to_string()),
-            transaction_root: Some(self.transactions_root.to_string()),
-            receipts_root: Some(self.receipts_root.to_string()),
-            logs_bloom: Some(hex::encode(self.logs_bloom)),
-            difficulty: Some(self.difficulty.to_string()),
-            totaldifficulty: None,
-            number: self.number.as_u64() as i64,
-            gas_limit: self.gas_limit.as_u64() as i64,
-            gas_used: self.gas_used.as_u64() as i64,
-            timestamp: Some(self.timestamp.to_string()),
-            extra_data: Some(hex::encode(self.extra_data)),
-            mix_hash: Some(self.mix_hash.to_string()),
-            nonce: hex::encode(self.nonce),
+    /// The database header, or `VerificationError::MalformedField` if `number`, `gas_limit` or
+    /// `gas_used` does not fit in the signed 64-bit columns that store them.
+    pub fn into_verifiable(self) -> Result<VerifiableBlockHeader, VerificationError> {
+        let ommers_hash = format!("{:?}", self.ommers_hash);
+        Ok(VerifiableBlockHeader {
+            block_hash: format!("{:?}", self.compute_hash()),
+            parent_hash: Some(format!("{:?}", self.parent_hash)),
+            ommers_hash: Some(ommers_hash.clone()),
+            miner: Some(format!("{:?}", self.beneficiary)),
+            state_root: Some(format!("{:?}", self.state_root)),
+            transaction_root: Some(format!("{:?}", self.transactions_root)),
+            receipts_root: Some(format!("{:?}", self.receipts_root)),
+            logs_bloom: Some(format!("0x{}", hex::encode(self.logs_bloom))),
+            difficulty: Some(format!("{:#x}", self.difficulty)),
+            totaldifficulty: None, // Not part of the header.
+            number: fields::to_integer("number", self.number)?,
+            gas_limit: fields::to_integer("gas_limit", self.gas_limit)?,
+            gas_used: fields::to_integer("gas_used", self.gas_used)?,
+            timestamp: Some(format!("{:#x}", self.timestamp)),
+            extra_data: Some(format!("0x{}", hex::encode(&self.extra_data))),
+            mix_hash: Some(format!("{:?}", self.mix_hash)),
+            nonce: format!("0x{}", hex::encode(self.nonce)),
             base_fee_per_gas: None,
             withdrawals_root: None,
             blob_gas_used: None,
             excess_blob_gas: None,
             parent_beacon_block_root: None,
-            sha3_uncles: None,
+            sha3_uncles: Some(ommers_hash),
             request_hash: None,
-        }
+        })
+    }
+}
+
+impl TryFrom<&VerifiableBlockHeader> for BlockHeaderGenesis {
+    type Error = VerificationError;
+
+    /// Parses a database block header, as stored by the indexer, into a `BlockHeaderGenesis`.
+    ///
+    /// # Returns
+    ///
+    /// The parsed header, or `VerificationError::MalformedField` naming the first column that is
+    /// missing or cannot be parsed.
+    fn try_from(db_header: &VerifiableBlockHeader) -> Result<Self, Self::Error> {
+        Ok(BlockHeaderGenesis {
+            parent_hash: fields::h256("parent_hash", &db_header.parent_hash)?,
+            ommers_hash: fields::h256("sha3_uncles", &db_header.sha3_uncles)?,
+            beneficiary: fields::h160("miner", &db_header.miner)?,
+            state_root: fields::h256("state_root", &db_header.state_root)?,
+            transactions_root: fields::h256("transaction_root", &db_header.transaction_root)?,
+            receipts_root: fields::h256("receipts_root", &db_header.receipts_root)?,
+            logs_bloom: fields::fixed_bytes::<256>(
+                "logs_bloom",
+                db_header.logs_bloom.as_deref().unwrap_or_default(),
+            )?,
+            difficulty: fields::u256_or_zero("difficulty", &db_header.difficulty)?,
+            number: fields::quantity("number", db_header.number)?,
+            gas_limit: fields::quantity("gas_limit", db_header.gas_limit)?,
+            gas_used: fields::quantity("gas_used", db_header.gas_used)?,
+            timestamp: fields::u256("timestamp", &db_header.timestamp)?,
+            extra_data: fields::bytes("extra_data", &db_header.extra_data)?,
+            mix_hash: fields::h256("mix_hash", &db_header.mix_hash)?,
+            nonce: fields::fixed_bytes::<8>("nonce", &db_header.nonce)?,
+        })
     }
 }
 
@@ -202,27 +222,35 @@ impl BlockHeaderTrait for BlockHeaderGenesis {
 ///
 /// # Returns
 ///
-/// A boolean value indicating whether the computed block hash matches the provided block hash.
-pub fn verify_hash_genesis(block_hash: String, db_header: VerifiableBlockHeader) -> bool {
-    // Store the block number before moving db_header
+/// `Ok(())` if the computed block hash matches the provided block hash, or a `VerificationError`
+/// describing the malformed field or the hash mismatch otherwise.
+pub fn verify_hash_genesis(
+    block_hash: String,
+    db_header: VerifiableBlockHeader,
+) -> Result<(), VerificationError> {
+    // Store the block number for error reporting
     let block_number = db_header.number;
-    let header = BlockHeaderGenesis::from_db_header(db_header);
+    let header = BlockHeaderGenesis::try_from(&db_header)?;
 
     // Compute the block hash
     let computed_block_hash = header.compute_hash();
-    let expected_hash = H256::from_str(&block_hash).unwrap();
+    let expected_hash =
+        H256::from_str(&block_hash).map_err(|e| VerificationError::malformed("block_hash", e))?;
 
     // Check if the computed hash matches the given block hash
-    let matches = computed_block_hash == expected_hash;
-
-    if !matches {
+    if computed_block_hash != expected_hash {
         error!(
             "Hash verification failed for Genesis block: expected {}, computed {}, block number {}",
             expected_hash, computed_block_hash, block_number
         );
+        return Err(VerificationError::HashMismatch {
+            block_number: block_number as u64,
+            expected: expected_hash,
+            computed: computed_block_hash,
+        });
     }
 
-    matches
+    Ok(())
 }
 
 #[cfg(test)]
@@ -0,0 +1,135 @@
+use super::{Era, ForkSchedule};
+use crate::error::VerificationError;
+use eth_rlp_types::BlockHeader as VerifiableBlockHeader;
+use ethereum_types::U256;
+use std::str::FromStr;
+
+/// Infers the era of a database block header from the optional fields it carries.
+///
+/// Every fork since London appended fields to the block header, so the set of populated columns
+/// identifies the header layout on its own: `base_fee_per_gas` from London, `withdrawals_root` from
+/// Shapella, `blob_gas_used`, `excess_blob_gas` and `parent_beacon_block_root` from Dencun, and
+/// `request_hash` from Pectra. London and Paris share a layout and are told apart by the difficulty,
+/// which is zero for every proof-of-stake block. Empty strings count as absent.
+///
+/// # Arguments
+///
+/// - `header`: The block header whose era should be inferred.
+///
+/// # Returns
+///
+/// The inferred `Era`, or `VerificationError::UnknownEra` if the populated fields do not form the layout
+/// of any era (e.g. a `withdrawals_root` without a `base_fee_per_gas`).
+pub fn infer_era(header: &VerifiableBlockHeader) -> Result<Era, VerificationError> {
+    let present = |value: &Option<String>| value.as_deref().is_some_and(|v| !v.is_empty());
+    let blob_fields = [
+        present(&header.blob_gas_used),
+        present(&header.excess_blob_gas),
+        present(&header.parent_beacon_block_root),
+    ];
+
+    // Each fork's fields may only be present if every earlier fork's fields are.
+    let layers = [
+        present(&header.base_fee_per_gas),
+        present(&header.withdrawals_root),
+        blob_fields.iter().all(|&p| p),
+        present(&header.request_hash),
+    ];
+    let depth = layers.iter().take_while(|&&p| p).count();
+    let partial_blob_fields = blob_fields.iter().any(|&p| p) && !layers[2];
+    if partial_blob_fields || layers[depth..].iter().any(|&p| p) {
+        return Err(VerificationError::UnknownEra {
+            block_number: header.number as u64,
+        });
+    }
+
+    Ok(match depth {
+        0 => Era::Genesis,
+        1 if is_zero_difficulty(header)? => Era::Paris,
+        1 => Era::London,
+        2 => Era::Shapella,
+        3 => Era::Dencun,
+        _ => Era::Pectra,
+    })
+}
+
+/// Infers the era of a header from its fields and checks it against a chain's fork schedule.
+///
+/// # Returns
+///
+/// The era of the header if its fields match the era `forks` assigns to its number and timestamp, or
+/// `VerificationError::EraMismatch` when the stored row carries the fields of a different era.
+pub fn check_era(
+    header: &VerifiableBlockHeader,
+    forks: &ForkSchedule,
+) -> Result<Era, VerificationError> {
+    let inferred = infer_era(header)?;
+    let scheduled = forks.era_at(header.number as u64, super::header_timestamp(header)?);
+
+    if inferred != scheduled {
+        return Err(VerificationError::EraMismatch {
+            block_number: header.number as u64,
+            scheduled,
+            inferred,
+        });
+    }
+
+    Ok(inferred)
+}
+
+fn is_zero_difficulty(header: &VerifiableBlockHeader) -> Result<bool, VerificationError> {
+    match header.difficulty.as_deref() {
+        Some(raw) if !raw.is_empty() => U256::from_str(raw)
+            .map(|difficulty| difficulty.is_zero())
+            .map_err(|e| VerificationError::malformed("difficulty", e)),
+        _ => Ok(true),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{
+        create_test_block_header_london, create_test_block_header_paris,
+        create_test_block_header_pectra_hoodi,
+    };
+
+    #[test]
+    fn test_infer_era_from_fields() {
+        assert_eq!(
+            infer_era(&create_test_block_header_london()),
+            Ok(Era::London)
+        );
+        assert_eq!(infer_era(&create_test_block_header_paris()), Ok(Era::Paris));
+        assert_eq!(
+            infer_era(&create_test_block_header_pectra_hoodi()),
+            Ok(Era::Pectra)
+        );
+
+        let mut header = create_test_block_header_pectra_hoodi();
+        header.blob_gas_used = None;
+        assert_eq!(
+            infer_era(&header),
+            Err(VerificationError::UnknownEra {
+                block_number: 411443
+            })
+        );
+    }
+
+    #[test]
+    fn test_check_era_against_schedule() {
+        let header = create_test_block_header_pectra_hoodi();
+        assert_eq!(check_era(&header, &ForkSchedule::HOODI), Ok(Era::Pectra));
+
+        let mut header = header;
+        header.request_hash = None;
+        assert_eq!(
+            check_era(&header, &ForkSchedule::HOODI),
+            Err(VerificationError::EraMismatch {
+                block_number: 411443,
+                scheduled: Era::Pectra,
+                inferred: Era::Dencun,
+            })
+        );
+    }
+}
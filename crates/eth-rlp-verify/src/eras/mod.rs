@@ -1,173 +1,111 @@
+mod any;
 mod dencun;
+mod fields;
 mod genesis;
+mod inference;
 mod london;
 mod paris;
 pub mod pectra;
+mod schedule;
 mod shapella;
 
-use crate::constants::*;
+use crate::error::VerificationError;
 use eth_rlp_types::{BlockHeader as VerifiableBlockHeader, BlockHeaderTrait};
 
-// Re-export each era's verification function to make them accessible at the module level.
-pub use dencun::verify_hash_dencun;
-pub use genesis::verify_hash_genesis;
-pub use london::verify_hash_london;
-pub use paris::verify_hash_paris;
-pub use pectra::verify_hash_pectra;
-pub use shapella::verify_hash_shapella;
+// Re-export each era's header struct and verification function to make them accessible at the module level.
+pub use any::{decode_any_header, EraHeader};
+pub use dencun::{verify_hash_dencun, BlockHeaderDencun};
+pub use genesis::{verify_hash_genesis, BlockHeaderGenesis};
+pub use inference::{check_era, infer_era};
+pub use london::{verify_hash_london, BlockHeaderLondon};
+pub use paris::{verify_hash_paris, BlockHeaderParis};
+pub use pectra::{verify_hash_pectra, BlockHeaderPectra};
+pub use schedule::{Era, ForkSchedule};
+pub use shapella::{verify_hash_shapella, BlockHeaderShapella};
 
+type EncoderFn = fn(&VerifiableBlockHeader) -> Result<Vec<u8>, VerificationError>;
 type DecoderFn = fn(&[u8]) -> Result<VerifiableBlockHeader, eyre::Report>;
 
-/// Determines the correct Ethereum era based on the block number and returns the corresponding
-/// hash verification function for that era.
+/// Determines the correct Ethereum era of a block and returns the corresponding hash verification
+/// function for that era.
 ///
 /// Ethereum undergoes upgrades (also known as "eras") that introduce significant changes to the protocol.
-/// Each era has its own block header format, which requires specialized verification logic. This function
-/// helps determine which era a block belongs to based on the block number and returns the appropriate
-/// function to verify the block's hash.
+/// Each era has its own block header format, which requires specialized verification logic. The era is
+/// resolved from the chain's `ForkSchedule` exactly like execution clients do: pre-Merge forks by block
+/// number and every fork since Shanghai by the header's timestamp.
 ///
 /// # Arguments
 ///
 /// - `block_number`: A `u64` representing the number of the block whose era needs to be determined.
+/// - `timestamp`: A `u64` holding the block's timestamp, used for timestamp-activated forks.
+/// - `forks`: The fork schedule of the chain the block belongs to, usually taken from its `ChainSpec`.
 ///
 /// # Returns
 ///
-/// An `Option<fn(String, VerifiableBlockHeader) -> bool>`, which is:
-/// - `Some(verification_function)` if the block number corresponds to a recognized era, where the
-///   returned function can be used to verify the block's hash.
-/// - `None` if the block number does not match any known era.
+/// A function that verifies the block's hash using the layout of the resolved era.
 ///
-/// # Supported Eras and Their Block Ranges:
+/// # Supported Eras and Their Activation:
 ///
-/// - **London:** The era between `LONDON_START` and `LONDON_END`, inclusive. This upgrade introduced EIP-1559, changing the gas fee model.
-/// - **Paris:** The era between `PARIS_START` and `PARIS_END`, representing Ethereum's merge from proof-of-work (PoW) to proof-of-stake (PoS).
-/// - **Shapella:** The era between `SHAPELLA_START` and `SHAPELLA_END`, introducing further changes to Ethereum's staking and withdrawal mechanisms.
-/// - **Dencun:** Blocks from `DENCUN_START` onwards, which represent the upcoming Dencun upgrade.
-/// - **Genesis:** The initial blocks from block 0 up to `GENESIS_END`, covering Ethereum's early history.
-///
-/// # Notes
-///
-/// - If the block number falls outside the recognized eras, this function will return `None`.
+/// - **Genesis:** Blocks before the London block, covering Ethereum's early history.
+/// - **London:** Blocks from the London block, which introduced EIP-1559 and changed the gas fee model.
+/// - **Paris:** Blocks from the merge block, representing Ethereum's move from proof-of-work (PoW) to proof-of-stake (PoS).
+/// - **Shapella:** Blocks timestamped at or after Shanghai, introducing staking withdrawals.
+/// - **Dencun:** Blocks timestamped at or after Cancun, introducing blob transactions (EIP-4844).
+/// - **Pectra:** Blocks timestamped at or after Prague, whose headers carry the EIP-7685 `requests_hash`.
 pub fn determine_era(
     block_number: u64,
-    chain_id: u64,
-) -> Option<fn(String, VerifiableBlockHeader) -> bool> {
-    match chain_id {
-        crate::CHAIN_ID_MAINNET => {
-            if (LONDON_START..=LONDON_END).contains(&block_number) {
-                Some(verify_hash_london)
-            } else if (PARIS_START..=PARIS_END).contains(&block_number) {
-                Some(verify_hash_paris)
-            } else if (SHAPELLA_START..=SHAPELLA_END).contains(&block_number) {
-                Some(verify_hash_shapella)
-            } else if block_number >= DENCUN_START {
-                Some(verify_hash_dencun)
-            } else if block_number <= GENESIS_END {
-                Some(verify_hash_genesis)
-            } else {
-                None
-            }
-        }
-        crate::CHAIN_ID_SEPOLIA => {
-            if block_number <= LONDON_END_SEPOLIA {
-                Some(verify_hash_london)
-            } else if (SHAPELLA_START_SEPOLIA..=SHAPELLA_END_SEPOLIA).contains(&block_number) {
-                Some(verify_hash_shapella)
-            } else if (DENCUN_START_SEPOLIA..=PECTRA_START_SEPOLIA - 1).contains(&block_number) {
-                Some(verify_hash_dencun)
-            } else if block_number >= PECTRA_START_SEPOLIA {
-                Some(verify_hash_pectra)
-            } else {
-                None
-            }
-        }
-        _ => None,
+    timestamp: u64,
+    forks: &ForkSchedule,
+) -> fn(String, VerifiableBlockHeader) -> Result<(), VerificationError> {
+    match forks.era_at(block_number, timestamp) {
+        Era::Genesis => verify_hash_genesis,
+        Era::London => verify_hash_london,
+        Era::Paris => verify_hash_paris,
+        Era::Shapella => verify_hash_shapella,
+        Era::Dencun => verify_hash_dencun,
+        Era::Pectra => verify_hash_pectra,
     }
 }
 
-pub fn determine_era_encoder(
-    block_number: u64,
-    chain_id: u64,
-) -> Option<fn(VerifiableBlockHeader) -> Vec<u8>> {
-    match chain_id {
-        crate::CHAIN_ID_MAINNET => {
-            if (LONDON_START..=LONDON_END).contains(&block_number) {
-                Some(|header| london::BlockHeaderLondon::from_db_header(header).rlp_encode())
-            } else if (PARIS_START..=PARIS_END).contains(&block_number) {
-                Some(|header| paris::BlockHeaderParis::from_db_header(header).rlp_encode())
-            } else if (SHAPELLA_START..=SHAPELLA_END).contains(&block_number) {
-                Some(|header| shapella::BlockHeaderShapella::from_db_header(header).rlp_encode())
-            } else if block_number >= DENCUN_START {
-                Some(|header| dencun::BlockHeaderDencun::from_db_header(header).rlp_encode())
-            } else if block_number <= GENESIS_END {
-                Some(|header| genesis::BlockHeaderGenesis::from_db_header(header).rlp_encode())
-            } else {
-                None
-            }
-        }
-        crate::CHAIN_ID_SEPOLIA => {
-            if block_number <= LONDON_END_SEPOLIA {
-                Some(|header| london::BlockHeaderLondon::from_db_header(header).rlp_encode())
-            } else if (SHAPELLA_START_SEPOLIA..=SHAPELLA_END_SEPOLIA).contains(&block_number) {
-                Some(|header| shapella::BlockHeaderShapella::from_db_header(header).rlp_encode())
-            } else if (DENCUN_START_SEPOLIA..=PECTRA_START_SEPOLIA - 1).contains(&block_number) {
-                Some(|header| dencun::BlockHeaderDencun::from_db_header(header).rlp_encode())
-            } else if block_number >= PECTRA_START_SEPOLIA {
-                Some(|header| pectra::BlockHeaderPectra::from_db_header(header).rlp_encode())
-            } else {
-                None
-            }
-        }
-        _ => None,
+/// Returns the RLP encoder for the era a block belongs to.
+///
+/// The returned encoder parses the database header into the era's struct first, so a missing or
+/// malformed field is reported as a `VerificationError` instead of panicking.
+pub fn determine_era_encoder(block_number: u64, timestamp: u64, forks: &ForkSchedule) -> EncoderFn {
+    match forks.era_at(block_number, timestamp) {
+        Era::Genesis => |header| Ok(genesis::BlockHeaderGenesis::try_from(header)?.rlp_encode()),
+        Era::London => |header| Ok(london::BlockHeaderLondon::try_from(header)?.rlp_encode()),
+        Era::Paris => |header| Ok(paris::BlockHeaderParis::try_from(header)?.rlp_encode()),
+        Era::Shapella => |header| Ok(shapella::BlockHeaderShapella::try_from(header)?.rlp_encode()),
+        Era::Dencun => |header| Ok(dencun::BlockHeaderDencun::try_from(header)?.rlp_encode()),
+        Era::Pectra => |header| Ok(pectra::BlockHeaderPectra::try_from(header)?.rlp_encode()),
     }
 }
 
-pub fn determine_era_decoder(block_number: u64, chain_id: u64) -> Option<DecoderFn> {
-    match chain_id {
-        crate::CHAIN_ID_MAINNET => {
-            if block_number <= GENESIS_END {
-                Some(|data| {
-                    genesis::BlockHeaderGenesis::rlp_decode(data).map(|h| h.into_verifiable())
-                })
-            } else if (LONDON_START..=LONDON_END).contains(&block_number) {
-                Some(|data| {
-                    london::BlockHeaderLondon::rlp_decode(data).map(|h| h.into_verifiable())
-                })
-            } else if (PARIS_START..=PARIS_END).contains(&block_number) {
-                Some(|data| paris::BlockHeaderParis::rlp_decode(data).map(|h| h.into_verifiable()))
-            } else if (SHAPELLA_START..=SHAPELLA_END).contains(&block_number) {
-                Some(|data| {
-                    shapella::BlockHeaderShapella::rlp_decode(data).map(|h| h.into_verifiable())
-                })
-            } else if block_number >= DENCUN_START {
-                Some(|data| {
-                    dencun::BlockHeaderDencun::rlp_decode(data).map(|h| h.into_verifiable())
-                })
-            } else {
-                None
-            }
+pub fn determine_era_decoder(block_number: u64, timestamp: u64, forks: &ForkSchedule) -> DecoderFn {
+    match forks.era_at(block_number, timestamp) {
+        Era::Genesis => {
+            |data| Ok(genesis::BlockHeaderGenesis::rlp_decode(data)?.into_verifiable()?)
         }
-        crate::CHAIN_ID_SEPOLIA => {
-            if block_number <= LONDON_END_SEPOLIA {
-                Some(|data| {
-                    london::BlockHeaderLondon::rlp_decode(data).map(|h| h.into_verifiable())
-                })
-            } else if (SHAPELLA_START_SEPOLIA..=SHAPELLA_END_SEPOLIA).contains(&block_number) {
-                Some(|data| {
-                    shapella::BlockHeaderShapella::rlp_decode(data).map(|h| h.into_verifiable())
-                })
-            } else if (DENCUN_START_SEPOLIA..=PECTRA_START_SEPOLIA - 1).contains(&block_number) {
-                Some(|data| {
-                    dencun::BlockHeaderDencun::rlp_decode(data).map(|h| h.into_verifiable())
-                })
-            } else if block_number >= PECTRA_START_SEPOLIA {
-                Some(|data| {
-                    pectra::BlockHeaderPectra::rlp_decode(data).map(|h| h.into_verifiable())
-                })
-            } else {
-                None
-            }
+        Era::London => |data| Ok(london::BlockHeaderLondon::rlp_decode(data)?.into_verifiable()?),
+        Era::Paris => |data| Ok(paris::BlockHeaderParis::rlp_decode(data)?.into_verifiable()?),
+        Era::Shapella => {
+            |data| Ok(shapella::BlockHeaderShapella::rlp_decode(data)?.into_verifiable()?)
         }
-        _ => None,
+        Era::Dencun => |data| Ok(dencun::BlockHeaderDencun::rlp_decode(data)?.into_verifiable()?),
+        Era::Pectra => |data| Ok(pectra::BlockHeaderPectra::rlp_decode(data)?.into_verifiable()?),
     }
 }
+
+/// Reads the timestamp of a database block header as a `u64`.
+///
+/// # Returns
+///
+/// The timestamp, or `VerificationError::MalformedField` if the timestamp is missing or not a valid
+/// 64-bit quantity.
+pub fn header_timestamp(header: &VerifiableBlockHeader) -> Result<u64, VerificationError> {
+    Ok(eth_rlp_types::fields::u64_quantity(
+        "timestamp",
+        &header.timestamp,
+    )?)
+}
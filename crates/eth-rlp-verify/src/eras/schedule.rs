@@ -0,0 +1,189 @@
+use crate::constants::*;
+
+/// The block header layouts supported by this crate.
+///
+/// Each variant corresponds to one of the era modules and is ordered chronologically, so eras can be
+/// compared with `<` and `>=` to reason about which fields a header is expected to carry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Era {
+    Genesis,
+    London,
+    Paris,
+    Shapella,
+    Dencun,
+    Pectra,
+}
+
+/// The activation points of the forks that change the block header layout, the proof-of-work difficulty
+/// rules or the transaction types a block may include on a given chain.
+///
+/// Pre-Merge forks are activated by block number, while every fork since Shanghai is activated by the
+/// header's timestamp, mirroring how execution clients schedule hard forks. A `None` entry means the
+/// fork is not scheduled on that chain.
+///
+/// # Fields
+///
+/// - `homestead_block`: The first block using the Homestead difficulty adjustment (EIP-2).
+/// - `byzantium_block`: The first block using the Byzantium difficulty adjustment, which accounts for
+///   ommers (EIP-100), and delaying the difficulty bomb by 3 million blocks (EIP-649).
+/// - `constantinople_block`: The first block delaying the difficulty bomb by 5 million blocks (EIP-1234).
+/// - `muir_glacier_block`: The first block delaying the difficulty bomb by 9 million blocks (EIP-2384).
+/// - `berlin_block`: The first block that may include access list transactions (type 1, EIP-2930).
+/// - `london_block`: The first block using the London layout (adds `base_fee_per_gas`) and delaying the
+///   difficulty bomb by 9.7 million blocks (EIP-3554).
+/// - `arrow_glacier_block`: The first block delaying the difficulty bomb by 10.7 million blocks (EIP-4345).
+/// - `gray_glacier_block`: The first block delaying the difficulty bomb by 11.4 million blocks (EIP-5133).
+/// - `paris_block`: The first proof-of-stake block. Execution clients switch on terminal total difficulty;
+///   since a lone header cannot prove that, the resulting merge block number is used instead.
+/// - `shanghai_time`: The timestamp at which the Shapella layout (adds `withdrawals_root`) activates.
+/// - `cancun_time`: The timestamp at which the Dencun layout (adds blob gas fields and the beacon root) activates.
+/// - `prague_time`: The timestamp at which the Pectra layout (adds `requests_hash`) activates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ForkSchedule {
+    pub homestead_block: Option<u64>,
+    pub byzantium_block: Option<u64>,
+    pub constantinople_block: Option<u64>,
+    pub muir_glacier_block: Option<u64>,
+    pub berlin_block: Option<u64>,
+    pub london_block: Option<u64>,
+    pub arrow_glacier_block: Option<u64>,
+    pub gray_glacier_block: Option<u64>,
+    pub paris_block: Option<u64>,
+    pub shanghai_time: Option<u64>,
+    pub cancun_time: Option<u64>,
+    pub prague_time: Option<u64>,
+}
+
+impl ForkSchedule {
+    /// The fork schedule of Ethereum mainnet.
+    pub const MAINNET: ForkSchedule = ForkSchedule {
+        homestead_block: Some(HOMESTEAD_START),
+        byzantium_block: Some(BYZANTIUM_START),
+        constantinople_block: Some(CONSTANTINOPLE_START),
+        muir_glacier_block: Some(MUIR_GLACIER_START),
+        berlin_block: Some(BERLIN_START),
+        london_block: Some(LONDON_START),
+        arrow_glacier_block: Some(ARROW_GLACIER_START),
+        gray_glacier_block: Some(GRAY_GLACIER_START),
+        paris_block: Some(PARIS_START),
+        shanghai_time: Some(SHANGHAI_TIMESTAMP),
+        cancun_time: Some(CANCUN_TIMESTAMP),
+        prague_time: Some(PRAGUE_TIMESTAMP),
+    };
+
+    /// The fork schedule of the Sepolia testnet.
+    pub const SEPOLIA: ForkSchedule = ForkSchedule {
+        homestead_block: Some(0),
+        byzantium_block: Some(0),
+        constantinople_block: Some(0),
+        muir_glacier_block: Some(0),
+        berlin_block: Some(0),
+        london_block: Some(LONDON_START_SEPOLIA),
+        arrow_glacier_block: None,
+        gray_glacier_block: None,
+        paris_block: Some(PARIS_START_SEPOLIA),
+        shanghai_time: Some(SHANGHAI_TIMESTAMP_SEPOLIA),
+        cancun_time: Some(CANCUN_TIMESTAMP_SEPOLIA),
+        prague_time: Some(PRAGUE_TIMESTAMP_SEPOLIA),
+    };
+
+    /// The fork schedule of the Holesky testnet.
+    pub const HOLESKY: ForkSchedule = ForkSchedule {
+        homestead_block: Some(0),
+        byzantium_block: Some(0),
+        constantinople_block: Some(0),
+        muir_glacier_block: Some(0),
+        berlin_block: Some(0),
+        london_block: Some(0),
+        arrow_glacier_block: Some(0),
+        gray_glacier_block: Some(0),
+        paris_block: Some(0),
+        shanghai_time: Some(SHANGHAI_TIMESTAMP_HOLESKY),
+        cancun_time: Some(CANCUN_TIMESTAMP_HOLESKY),
+        prague_time: Some(PRAGUE_TIMESTAMP_HOLESKY),
+    };
+
+    /// The fork schedule of the Hoodi testnet.
+    pub const HOODI: ForkSchedule = ForkSchedule {
+        homestead_block: Some(0),
+        byzantium_block: Some(0),
+        constantinople_block: Some(0),
+        muir_glacier_block: Some(0),
+        berlin_block: Some(0),
+        london_block: Some(0),
+        arrow_glacier_block: Some(0),
+        gray_glacier_block: Some(0),
+        paris_block: Some(0),
+        shanghai_time: Some(0),
+        cancun_time: Some(0),
+        prague_time: Some(PRAGUE_TIMESTAMP_HOODI),
+    };
+
+    /// Resolves the era of a header from its block number and timestamp.
+    ///
+    /// Timestamp-activated forks are checked first, newest to oldest, followed by the block-activated
+    /// forks, so the most recent fork whose activation condition is met wins.
+    ///
+    /// # Arguments
+    ///
+    /// - `block_number`: The header's `number` field.
+    /// - `timestamp`: The header's `timestamp` field.
+    ///
+    /// # Returns
+    ///
+    /// The `Era` whose header layout applies to the block.
+    pub fn era_at(&self, block_number: u64, timestamp: u64) -> Era {
+        let at_time = |activation: Option<u64>| activation.is_some_and(|t| timestamp >= t);
+        let at_block = |activation: Option<u64>| activation.is_some_and(|b| block_number >= b);
+
+        if at_time(self.prague_time) {
+            Era::Pectra
+        } else if at_time(self.cancun_time) {
+            Era::Dencun
+        } else if at_time(self.shanghai_time) {
+            Era::Shapella
+        } else if at_block(self.paris_block) {
+            Era::Paris
+        } else if at_block(self.london_block) {
+            Era::London
+        } else {
+            Era::Genesis
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mainnet_fork_boundaries() {
+        let forks = ForkSchedule::MAINNET;
+        assert_eq!(forks.era_at(GENESIS_END, 0), Era::Genesis);
+        assert_eq!(forks.era_at(LONDON_START, 0), Era::London);
+        assert_eq!(forks.era_at(PARIS_START, 0), Era::Paris);
+        assert_eq!(
+            forks.era_at(SHAPELLA_START, SHANGHAI_TIMESTAMP - 1),
+            Era::Paris
+        );
+        assert_eq!(
+            forks.era_at(SHAPELLA_START, SHANGHAI_TIMESTAMP),
+            Era::Shapella
+        );
+        assert_eq!(forks.era_at(DENCUN_START, CANCUN_TIMESTAMP), Era::Dencun);
+        assert_eq!(
+            forks.era_at(PECTRA_START, PRAGUE_TIMESTAMP - 1),
+            Era::Dencun
+        );
+        assert_eq!(forks.era_at(PECTRA_START, PRAGUE_TIMESTAMP), Era::Pectra);
+    }
+
+    #[test]
+    fn test_unscheduled_forks_stay_inactive() {
+        let forks = ForkSchedule {
+            london_block: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(forks.era_at(u64::MAX, u64::MAX), Era::London);
+    }
+}
@@ -0,0 +1,208 @@
+use crate::consensus::ConsensusError;
+use crate::continuity::ContinuityBreak;
+use crate::eras::Era;
+use eth_rlp_types::FieldError;
+use ethereum_types::H256;
+use std::fmt;
+
+/// Describes why a block header, or a sequence of block headers, failed verification.
+///
+/// Every verification entry point returns this error instead of a bare `bool`, so callers can
+/// categorise and persist failure reasons rather than scraping logs.
+///
+/// # Variants
+///
+/// - `UnknownChain`: No `ChainSpec` is built in or registered for the chain id.
+/// - `UnknownEra`: The populated fields of the header do not form the layout of any era.
+/// - `EraMismatch`: The fields present in the header belong to a different era than the one the chain's
+///   fork schedule assigns to it.
+/// - `MalformedField`: A header field is missing or cannot be parsed; `field` names the offending column.
+/// - `HashMismatch`: The hash computed from the header differs from the expected block hash.
+/// - `ParentHashMismatch`: The `parent_hash` of the header at `index` does not match the hash of the
+///   header before it.
+/// - `ConsensusRule`: The header at `index` breaks a consensus rule with respect to the header before it.
+/// - `MergeTransitionNotFound`: The headers do not contain the last proof-of-work block, its parent and
+///   the first proof-of-stake block, or the chain has no terminal total difficulty.
+/// - `MergeBlockMismatch`: The first proof-of-stake block found is not the chain's scheduled Paris block.
+/// - `RootMismatch`: A root computed from a block body (e.g. its transactions) differs from the one
+///   committed to by the header; `field` names the header field.
+/// - `LogsBloomMismatch`: A logs bloom does not match the logs it covers: that of the receipt at
+///   `receipt` if set, otherwise the `logs_bloom` of the header.
+/// - `InvalidOmmers`: The ommers included by a block do not match its `ommers_hash` or break an ommer rule.
+/// - `InvalidSeal`: The proof-of-work seal of a pre-Merge header is invalid.
+/// - `ContinuityBreak`: The headers are not a contiguous segment: a block number is skipped or repeated,
+///   or a timestamp does not increase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerificationError {
+    UnknownChain(u64),
+    UnknownEra {
+        block_number: u64,
+    },
+    EraMismatch {
+        block_number: u64,
+        scheduled: Era,
+        inferred: Era,
+    },
+    MalformedField {
+        field: &'static str,
+        reason: String,
+    },
+    HashMismatch {
+        block_number: u64,
+        expected: H256,
+        computed: H256,
+    },
+    ParentHashMismatch {
+        index: usize,
+        expected: String,
+        found: String,
+    },
+    ConsensusRule {
+        index: usize,
+        source: ConsensusError,
+    },
+    MergeTransitionNotFound,
+    MergeBlockMismatch {
+        expected: u64,
+        found: u64,
+    },
+    RootMismatch {
+        block_number: u64,
+        field: &'static str,
+        expected: H256,
+        computed: H256,
+    },
+    LogsBloomMismatch {
+        block_number: u64,
+        receipt: Option<usize>,
+    },
+    InvalidOmmers {
+        block_number: u64,
+        source: ConsensusError,
+    },
+    InvalidSeal {
+        block_number: u64,
+        source: ConsensusError,
+    },
+    ContinuityBreak(ContinuityBreak),
+}
+
+impl VerificationError {
+    /// Builds a `MalformedField` error for the given header field.
+    pub fn malformed(field: &'static str, reason: impl fmt::Display) -> Self {
+        VerificationError::MalformedField {
+            field,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerificationError::UnknownChain(chain_id) => {
+                write!(f, "no chain spec is known for chain id {}", chain_id)
+            }
+            VerificationError::UnknownEra { block_number } => {
+                write!(f, "could not determine the era of block {}", block_number)
+            }
+            VerificationError::EraMismatch {
+                block_number,
+                scheduled,
+                inferred,
+            } => write!(
+                f,
+                "block {} is scheduled for the {:?} era but its fields belong to {:?}",
+                block_number, scheduled, inferred
+            ),
+            VerificationError::MalformedField { field, reason } => {
+                write!(f, "malformed field `{}`: {}", field, reason)
+            }
+            VerificationError::HashMismatch {
+                block_number,
+                expected,
+                computed,
+            } => write!(
+                f,
+                "hash mismatch for block {}: expected {:?}, computed {:?}",
+                block_number, expected, computed
+            ),
+            VerificationError::ParentHashMismatch {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "parent hash mismatch at index {}: expected {}, found {}",
+                index, expected, found
+            ),
+            VerificationError::ConsensusRule { index, source } => {
+                write!(f, "consensus rule broken at index {}: {}", index, source)
+            }
+            VerificationError::MergeTransitionNotFound => write!(
+                f,
+                "the headers do not contain a transition from proof-of-work to proof-of-stake"
+            ),
+            VerificationError::MergeBlockMismatch { expected, found } => write!(
+                f,
+                "the first proof-of-stake block is {}, but the chain schedules Paris at block {}",
+                found, expected
+            ),
+            VerificationError::RootMismatch {
+                block_number,
+                field,
+                expected,
+                computed,
+            } => write!(
+                f,
+                "`{}` mismatch for block {}: expected {:?}, computed {:?}",
+                field, block_number, expected, computed
+            ),
+            VerificationError::LogsBloomMismatch {
+                block_number,
+                receipt: Some(index),
+            } => write!(
+                f,
+                "the logs bloom of receipt {} of block {} does not match its logs",
+                index, block_number
+            ),
+            VerificationError::LogsBloomMismatch {
+                block_number,
+                receipt: None,
+            } => write!(
+                f,
+                "`logs_bloom` mismatch for block {}: the header does not match the logs of its receipts",
+                block_number
+            ),
+            VerificationError::InvalidOmmers {
+                block_number,
+                source,
+            } => write!(f, "invalid ommers in block {}: {}", block_number, source),
+            VerificationError::InvalidSeal {
+                block_number,
+                source,
+            } => write!(f, "invalid seal for block {}: {}", block_number, source),
+            VerificationError::ContinuityBreak(found) => write!(f, "{}", found),
+        }
+    }
+}
+
+impl From<FieldError> for VerificationError {
+    fn from(error: FieldError) -> Self {
+        VerificationError::MalformedField {
+            field: error.field,
+            reason: error.reason,
+        }
+    }
+}
+
+impl std::error::Error for VerificationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            VerificationError::ConsensusRule { source, .. }
+            | VerificationError::InvalidOmmers { source, .. }
+            | VerificationError::InvalidSeal { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
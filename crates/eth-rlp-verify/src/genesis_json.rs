@@ -0,0 +1,227 @@
+use crate::chain_spec::ChainSpec;
+use crate::eras::ForkSchedule;
+use ethereum_types::U256;
+use eyre::{eyre, Result};
+use serde::{Deserialize, Deserializer};
+use serde_json::value::RawValue;
+use std::path::Path;
+
+/// The `config` section of a geth-style `genesis.json`.
+///
+/// Only the fields that influence the block header layout or the proof-of-work difficulty are read; every
+/// other entry (other fork blocks, the blob schedule, clique/ethash settings, ...) is ignored. Quantities may be given either as
+/// JSON numbers or as decimal/hexadecimal strings, since devnet tooling emits both.
+///
+/// # Fields
+///
+/// - `chain_id`: The chain id of the network.
+/// - `homestead_block`, `byzantium_block`, `constantinople_block`, `muir_glacier_block`: The blocks at which
+///   the pre-London forks that changed the difficulty rules activate.
+/// - `berlin_block`: The block at which Berlin activates, allowing access list transactions.
+/// - `london_block`: The block at which London activates.
+/// - `arrow_glacier_block`, `gray_glacier_block`: The blocks at which the post-London difficulty bomb
+///   delays activate.
+/// - `merge_netsplit_block`: The first proof-of-stake block, when the generator recorded it.
+/// - `terminal_total_difficulty`: The difficulty at which the Merge happens. A value of zero means the chain
+///   is proof-of-stake from genesis.
+/// - `shanghai_time`, `cancun_time`, `prague_time`: The activation timestamps of the timestamp-based forks.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenesisConfig {
+    #[serde(deserialize_with = "quantity")]
+    pub chain_id: u64,
+    #[serde(default, deserialize_with = "optional_quantity")]
+    pub homestead_block: Option<u64>,
+    #[serde(default, deserialize_with = "optional_quantity")]
+    pub byzantium_block: Option<u64>,
+    #[serde(default, deserialize_with = "optional_quantity")]
+    pub constantinople_block: Option<u64>,
+    #[serde(default, deserialize_with = "optional_quantity")]
+    pub muir_glacier_block: Option<u64>,
+    #[serde(default, deserialize_with = "optional_quantity")]
+    pub berlin_block: Option<u64>,
+    #[serde(default, deserialize_with = "optional_quantity")]
+    pub london_block: Option<u64>,
+    #[serde(default, deserialize_with = "optional_quantity")]
+    pub arrow_glacier_block: Option<u64>,
+    #[serde(default, deserialize_with = "optional_quantity")]
+    pub gray_glacier_block: Option<u64>,
+    #[serde(default, deserialize_with = "optional_quantity")]
+    pub merge_netsplit_block: Option<u64>,
+    #[serde(default, deserialize_with = "optional_big_quantity")]
+    pub terminal_total_difficulty: Option<U256>,
+    #[serde(default, deserialize_with = "optional_quantity")]
+    pub shanghai_time: Option<u64>,
+    #[serde(default, deserialize_with = "optional_quantity")]
+    pub cancun_time: Option<u64>,
+    #[serde(default, deserialize_with = "optional_quantity")]
+    pub prague_time: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct GenesisFile {
+    config: GenesisConfig,
+}
+
+impl GenesisConfig {
+    /// Parses the `config` section out of the contents of a `genesis.json` file.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let genesis: GenesisFile = serde_json::from_str(json)?;
+        Ok(genesis.config)
+    }
+
+    /// Builds the fork schedule described by this configuration.
+    ///
+    /// The Paris block is taken from `mergeNetsplitBlock` when present. Otherwise a terminal total
+    /// difficulty of zero means the chain was merged at genesis; any other value leaves Paris unscheduled,
+    /// because the merge block cannot be derived from the configuration alone.
+    pub fn fork_schedule(&self) -> ForkSchedule {
+        let paris_block = self.merge_netsplit_block.or_else(|| {
+            self.terminal_total_difficulty
+                .filter(|ttd| ttd.is_zero())
+                .map(|_| 0)
+        });
+
+        ForkSchedule {
+            homestead_block: self.homestead_block,
+            byzantium_block: self.byzantium_block,
+            constantinople_block: self.constantinople_block,
+            muir_glacier_block: self.muir_glacier_block,
+            berlin_block: self.berlin_block,
+            london_block: self.london_block,
+            arrow_glacier_block: self.arrow_glacier_block,
+            gray_glacier_block: self.gray_glacier_block,
+            paris_block,
+            shanghai_time: self.shanghai_time,
+            cancun_time: self.cancun_time,
+            prague_time: self.prague_time,
+        }
+    }
+}
+
+impl ChainSpec {
+    /// Builds a chain spec from the contents of a geth-style `genesis.json`.
+    ///
+    /// The genesis hash is left unset, as computing it requires the full genesis state.
+    pub fn from_genesis_json(json: &str) -> Result<Self> {
+        let config = GenesisConfig::from_json(json)?;
+        Ok(ChainSpec {
+            chain_id: config.chain_id,
+            genesis_hash: None,
+            forks: config.fork_schedule(),
+            terminal_total_difficulty: config.terminal_total_difficulty,
+        })
+    }
+
+    /// Reads a geth-style `genesis.json` from disk and builds a chain spec from it.
+    pub fn from_genesis_file(path: impl AsRef<Path>) -> Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Self::from_genesis_json(&json)
+    }
+}
+
+/// Parses a quantity from its JSON text, so that numbers larger than 64 bits (such as a terminal total
+/// difficulty) keep their exact value instead of going through a float.
+fn parse_big_quantity(raw: &RawValue) -> Result<U256> {
+    let text = raw.get();
+    if text.starts_with('"') {
+        let text: String = serde_json::from_str(text)?;
+        return match text.strip_prefix("0x") {
+            Some(hex) => Ok(U256::from_str_radix(hex, 16)?),
+            None => Ok(U256::from_dec_str(&text)?),
+        };
+    }
+    U256::from_dec_str(text)
+        .map_err(|_| eyre!("expected a number or a numeric string, got {}", text))
+}
+
+fn parse_quantity(raw: &RawValue) -> Result<u64> {
+    let quantity = parse_big_quantity(raw)?;
+    if quantity > U256::from(u64::MAX) {
+        return Err(eyre!("quantity {} does not fit in 64 bits", quantity));
+    }
+    Ok(quantity.as_u64())
+}
+
+fn quantity<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    let raw = Box::<RawValue>::deserialize(deserializer)?;
+    parse_quantity(&raw).map_err(serde::de::Error::custom)
+}
+
+fn optional_quantity<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+    match Option::<Box<RawValue>>::deserialize(deserializer)? {
+        Some(raw) => parse_quantity(&raw)
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        None => Ok(None),
+    }
+}
+
+fn optional_big_quantity<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<U256>, D::Error> {
+    match Option::<Box<RawValue>>::deserialize(deserializer)? {
+        Some(raw) => parse_big_quantity(&raw)
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eras::Era;
+
+    const KURTOSIS_GENESIS: &str = r#"{
+        "config": {
+            "chainId": 3151908,
+            "homesteadBlock": 0,
+            "eip150Block": 0,
+            "byzantiumBlock": 0,
+            "berlinBlock": 0,
+            "londonBlock": 0,
+            "mergeNetsplitBlock": 0,
+            "terminalTotalDifficulty": 0,
+            "terminalTotalDifficultyPassed": true,
+            "shanghaiTime": 0,
+            "cancunTime": 0,
+            "pragueTime": "0x6553f100",
+            "blobSchedule": {
+                "cancun": { "target": 3, "max": 6, "baseFeeUpdateFraction": 3338477 }
+            }
+        },
+        "nonce": "0x0",
+        "timestamp": "0x65fc00f0",
+        "gasLimit": "0x1c9c380",
+        "difficulty": "0x0",
+        "alloc": {}
+    }"#;
+
+    #[test]
+    fn test_chain_spec_from_genesis_json() {
+        let spec = ChainSpec::from_genesis_json(KURTOSIS_GENESIS).unwrap();
+
+        assert_eq!(spec.chain_id, 3151908);
+        assert_eq!(spec.genesis_hash, None);
+        assert_eq!(spec.forks.prague_time, Some(1_700_000_000));
+        assert_eq!(spec.forks.byzantium_block, Some(0));
+        assert_eq!(spec.forks.berlin_block, Some(0));
+        assert_eq!(spec.forks.constantinople_block, None);
+        assert_eq!(spec.forks.era_at(1, 1_699_999_999), Era::Dencun);
+        assert_eq!(spec.forks.era_at(2, 1_700_000_000), Era::Pectra);
+    }
+
+    #[test]
+    fn test_unmerged_genesis_leaves_paris_unscheduled() {
+        let json = r#"{ "config": { "chainId": 1337, "londonBlock": 5, "terminalTotalDifficulty": 58750000000000000000001 } }"#;
+        let config = GenesisConfig::from_json(json).unwrap();
+
+        assert_eq!(
+            config.terminal_total_difficulty,
+            Some(U256::from_dec_str("58750000000000000000001").unwrap())
+        );
+        assert_eq!(config.fork_schedule().paris_block, None);
+        assert_eq!(config.fork_schedule().era_at(5, 0), Era::London);
+    }
+}
@@ -0,0 +1,11 @@
+use ethereum_types::H256;
+use tiny_keccak::{Hasher, Keccak};
+
+/// Computes the Keccak256 hash of `data`.
+pub(crate) fn keccak256(data: &[u8]) -> H256 {
+    let mut hasher = Keccak::v256();
+    let mut output = [0u8; 32];
+    hasher.update(data);
+    hasher.finalize(&mut output);
+    H256(output)
+}
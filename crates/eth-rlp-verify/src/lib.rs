@@ -1,63 +1,159 @@
 #![deny(unused_crate_dependencies)]
 
+pub mod bloom;
+pub mod chain_spec;
+pub mod consensus;
 pub mod constants;
+pub mod continuity;
+pub mod diagnostics;
 pub mod eras;
+pub mod error;
+pub mod genesis_json;
+mod hash;
+pub mod merge;
+pub mod ommers;
+pub mod receipts;
+pub mod requests;
 pub mod test_helpers;
 pub mod traits;
-use eth_rlp_types::BlockHeader as VerifiableBlockHeader;
+pub mod transactions;
+pub mod trie;
+pub mod withdrawals;
+pub use bloom::{may_contain_logs, verify_logs_bloom, LogsBloom};
+pub use chain_spec::{
+    register_chain_spec, ChainSpec, CHAIN_ID_HOLESKY, CHAIN_ID_HOODI, CHAIN_ID_MAINNET,
+    CHAIN_ID_SEPOLIA,
+};
+pub use consensus::{ChainValidationOptions, ConsensusError, Ethash};
+pub use continuity::{find_continuity_breaks, ContinuityBreak};
+pub use diagnostics::{diff_header_against_rlp, diff_headers, FieldDiff, HeaderDiff};
+pub use eras::{decode_any_header, EraHeader};
+pub use error::VerificationError;
+use eth_rlp_types::{BlockHeader as VerifiableBlockHeader, ExecutionHeader};
+use ethereum_types::{H256, U256};
+pub use merge::validate_merge_transition;
+pub use ommers::{ommers_hash, verify_ommers};
+pub use receipts::{receipts_root, verify_receipts_root, Log, Receipt, ReceiptOutcome};
+pub use requests::{requests_hash, verify_requests_hash, ExecutionRequests};
+use std::str::FromStr;
 use tracing::error;
+pub use transactions::{transactions_root, verify_transactions_root};
+pub use withdrawals::{verify_withdrawals_root, withdrawals_root, Withdrawal};
 
-pub const CHAIN_ID_MAINNET: u64 = 1;
-pub const CHAIN_ID_SEPOLIA: u64 = 11155111;
+pub fn are_blocks_and_chain_valid(
+    block_headers: &[VerifiableBlockHeader],
+    spec: &ChainSpec,
+) -> Result<(), VerificationError> {
+    are_blocks_and_chain_valid_with(block_headers, spec, &ChainValidationOptions::default())
+}
+
+/// Verifies a contiguous segment of block headers and, optionally, the consensus rules linking them.
+///
+/// Every header is verified with [`verify_block`] and must reference the hash of the header before it.
+/// On top of that, `options` selects consensus rules that are checked between each header and its
+/// parent, such as the EIP-1559 base fee, and whether block numbers and timestamps must be contiguous.
+/// With `allow_gaps`, a header that skips ahead starts a new segment; each segment is then verified on
+/// its own. Use [`find_continuity_breaks`] to list where the segments start.
+///
+/// # Arguments
+///
+/// - `block_headers`: The headers to verify, ordered by block number.
+/// - `spec`: The `ChainSpec` of the chain the headers belong to.
+/// - `options`: The consensus rules to enforce between consecutive headers.
+///
+/// # Returns
+///
+/// `Ok(())` if the segment is valid, or the first `VerificationError` found. Broken consensus rules are
+/// reported as `VerificationError::ConsensusRule` with the index of the offending header.
+pub fn are_blocks_and_chain_valid_with(
+    block_headers: &[VerifiableBlockHeader],
+    spec: &ChainSpec,
+    options: &ChainValidationOptions,
+) -> Result<(), VerificationError> {
+    let mut previous_typed: Option<ExecutionHeader> = None;
+    let mut previous_total_difficulty: Option<U256> = None;
 
-pub fn are_blocks_and_chain_valid(block_headers: &[VerifiableBlockHeader], chain_id: u64) -> bool {
     for (i, block) in block_headers.iter().enumerate() {
         let block_hash = block.block_hash.clone();
         let parent_hash = block.parent_hash.clone().unwrap_or_default();
         let block_number = block.number;
 
-        let is_valid = verify_block(block_number as u64, block.clone(), &block_hash, chain_id);
-
-        if !is_valid {
-            return false;
-        }
+        verify_block(block_number as u64, block.clone(), &block_hash, spec)?;
 
         if i != 0 {
             let previous_block = &block_headers[i - 1];
-            let previous_block_hash = previous_block.block_hash.clone();
+            let continuity = continuity::continuity_break(previous_block, block, i)?;
+
+            if options.allow_gaps && matches!(continuity, Some(ContinuityBreak::Gap { .. })) {
+                // A new segment starts here: it is not linked to, nor validated against, the previous one.
+                previous_typed = None;
+                previous_total_difficulty = None;
+            } else {
+                if let Some(found) = continuity.filter(|_| options.check_continuity) {
+                    error!("Chain validation failed: {}", found);
+                    return Err(VerificationError::ContinuityBreak(found));
+                }
+
+                let previous_block_hash = previous_block.block_hash.clone();
+                if parent_hash != previous_block_hash {
+                    error!(
+                        "Chain validation failed: parent hash mismatch at block {}: expected {}, got {}",
+                        block_number, previous_block_hash, parent_hash
+                    );
+                    return Err(VerificationError::ParentHashMismatch {
+                        index: i,
+                        expected: previous_block_hash,
+                        found: parent_hash,
+                    });
+                }
+            }
+        }
 
-            if parent_hash != previous_block_hash {
+        if options.checks_consensus() {
+            let broken = |source: ConsensusError| {
                 error!(
-                    "Chain validation failed: parent hash mismatch at block {}: expected {}, got {}",
-                    block_number, previous_block_hash, parent_hash
+                    "Chain validation failed: consensus rule broken at block {}: {}",
+                    block_number, source
                 );
-                return false;
+                VerificationError::ConsensusRule { index: i, source }
+            };
+
+            let typed = ExecutionHeader::try_from(block)?;
+            consensus::validate_child(previous_typed.as_ref(), &typed, spec, options)
+                .map_err(broken)?;
+
+            if options.check_total_difficulty {
+                let total_difficulty = total_difficulty(block)?;
+                if let (Some(parent), Some(child)) = (previous_total_difficulty, total_difficulty) {
+                    consensus::validate_total_difficulty(parent, &typed, child).map_err(broken)?;
+                }
+                previous_total_difficulty = total_difficulty;
             }
+            previous_typed = Some(typed);
         }
     }
 
-    true
+    Ok(())
 }
 
-pub fn are_blocks_valid(block_headers: &[VerifiableBlockHeader], chain_id: u64) -> bool {
+pub fn are_blocks_valid(
+    block_headers: &[VerifiableBlockHeader],
+    spec: &ChainSpec,
+) -> Result<(), VerificationError> {
     for block in block_headers.iter() {
         let block_hash = block.block_hash.clone();
         let block_number = block.number;
 
-        let is_valid = verify_block(block_number as u64, block.clone(), &block_hash, chain_id);
-
-        if !is_valid {
-            return false;
-        }
+        verify_block(block_number as u64, block.clone(), &block_hash, spec)?;
     }
 
-    true
+    Ok(())
 }
 
 /// Verifies the validity of an Ethereum block header based on the block number and expected hash.
 ///
-/// This function determines the appropriate Ethereum era based on the block number, retrieves the corresponding
-/// verification function, and verifies the block header by comparing its computed hash with the expected block hash.
+/// This function determines the appropriate Ethereum era based on the block number and the header's timestamp,
+/// retrieves the corresponding verification function, and verifies the block header by comparing its computed hash with the expected block hash.
 /// The verification process ensures that the block is authentic and belongs to the correct place in the blockchain.
 ///
 /// # Arguments
@@ -65,48 +161,134 @@ pub fn are_blocks_valid(block_headers: &[VerifiableBlockHeader], chain_id: u64)
 /// - `block_number`: A `u64` representing the block number of the block being verified.
 /// - `block_header`: A `VerifiableBlockHeader` struct containing the block header data that needs to be verified.
 /// - `block_hash`: A string slice representing the expected hash of the block.
+/// - `spec`: The `ChainSpec` of the chain the block belongs to. If it carries a genesis hash, block 0
+///   must match it.
 ///
 /// # Returns
 ///
-/// A `bool` indicating whether the block header is valid.
+/// `Ok(())` if the block header is valid, or a `VerificationError` explaining why it is not.
 pub fn verify_block(
     block_number: u64,
     block_header: VerifiableBlockHeader,
     block_hash: &str,
-    chain_id: u64,
-) -> bool {
-    match eras::determine_era(block_number, chain_id) {
-        Some(verify_fn) => verify_fn(block_hash.to_string(), block_header),
-        None => false,
+    spec: &ChainSpec,
+) -> Result<(), VerificationError> {
+    let timestamp = eras::header_timestamp(&block_header)?;
+
+    let verify_fn = eras::determine_era(block_number, timestamp, &spec.forks);
+    verify_fn(block_hash.to_string(), block_header)?;
+
+    if block_number == 0 {
+        if let Some(genesis_hash) = spec.genesis_hash {
+            // The header has just been verified to hash to `block_hash`.
+            let computed = H256::from_str(block_hash)
+                .map_err(|e| VerificationError::malformed("block_hash", e))?;
+            if computed != genesis_hash {
+                error!(
+                    "Genesis hash mismatch for chain {}: expected {:?}, got {:?}",
+                    spec.chain_id, genesis_hash, computed
+                );
+                return Err(VerificationError::HashMismatch {
+                    block_number,
+                    expected: genesis_hash,
+                    computed,
+                });
+            }
+        }
     }
+
+    Ok(())
+}
+
+/// Verifies a block header using only the header itself and the chain it belongs to.
+///
+/// Unlike [`verify_block`], the block number and expected hash are read from the header's own `number`
+/// and `block_hash` columns. Before hashing, the era is inferred from the optional fields the header
+/// carries and cross-checked against the chain's fork schedule, so a database row whose columns do not
+/// match its era is reported as such rather than as an opaque hash mismatch.
+///
+/// # Arguments
+///
+/// - `block_header`: The block header to verify.
+/// - `spec`: The `ChainSpec` of the chain the block belongs to.
+///
+/// # Returns
+///
+/// `Ok(())` if the header is consistent with its era and hashes to its `block_hash`, or a
+/// `VerificationError` explaining why it does not.
+pub fn verify_header(
+    block_header: &VerifiableBlockHeader,
+    spec: &ChainSpec,
+) -> Result<(), VerificationError> {
+    eras::check_era(block_header, &spec.forks)?;
+    verify_block(
+        block_header.number as u64,
+        block_header.clone(),
+        &block_header.block_hash,
+        spec,
+    )
+}
+
+/// Verifies the Ethash proof-of-work seal of a pre-Merge block header.
+///
+/// This is an opt-in check on top of [`verify_header`]: the hash only proves that the header was not
+/// altered, while the seal proves that work was spent on it. The check derives the epoch's light cache
+/// locally, which takes a few seconds the first time an epoch is seen; pass the same `Ethash` for every
+/// header so the cache is reused.
+///
+/// # Arguments
+///
+/// - `block_header`: The pre-Merge block header whose seal should be verified.
+/// - `ethash`: The verifier holding the light cache of the most recent epoch.
+///
+/// # Returns
+///
+/// `Ok(())` if the seal is valid, `VerificationError::InvalidSeal` if it is not (including for
+/// proof-of-stake headers, which carry no work), or `VerificationError::MalformedField` if the header
+/// cannot be parsed.
+pub fn verify_pow_seal(
+    block_header: &VerifiableBlockHeader,
+    ethash: &mut Ethash,
+) -> Result<(), VerificationError> {
+    let header = ExecutionHeader::try_from(block_header)?;
+    ethash
+        .verify_seal(&header)
+        .map_err(|source| VerificationError::InvalidSeal {
+            block_number: header.number,
+            source,
+        })
 }
 
 /// Encodes an Ethereum block header into RLP format.
 ///
-/// This function determines the correct era based on the block number and encodes
-/// the block header accordingly.
+/// This function determines the correct era based on the block number and the header's
+/// timestamp, and encodes the block header accordingly.
 ///
 /// # Returns
 ///
-/// An `Option<Vec<u8>>` containing the RLP-encoded block header data if successful.
+/// An `Option<Vec<u8>>` containing the RLP-encoded block header data, or `None` if the header
+/// has no valid timestamp or one of its fields is missing or malformed.
 pub fn encode_block_header(
     block_number: u64,
     block_header: VerifiableBlockHeader,
-    chain_id: u64,
+    spec: &ChainSpec,
 ) -> Option<Vec<u8>> {
-    eras::determine_era_encoder(block_number, chain_id).map(|encoder| encoder(block_header))
+    let timestamp = eras::header_timestamp(&block_header).ok()?;
+    let encoder = eras::determine_era_encoder(block_number, timestamp, &spec.forks);
+    encoder(&block_header).ok()
 }
 
 /// Decodes an RLP-encoded block header based on the block number.
 ///
-/// This function determines the correct era for the given block number and decodes the RLP-encoded
-/// data into a `VerifiableBlockHeader`. If the block number is not within a recognized era or decoding
-/// fails, it returns `None`.
+/// This function reads the timestamp from the RLP-encoded header itself, determines the correct era for
+/// the given block number and timestamp, and decodes the data into a `VerifiableBlockHeader`. If the
+/// timestamp cannot be read or decoding fails, it returns `None`.
 ///
 /// # Arguments
 ///
 /// - `block_number`: A `u64` representing the block number of the block being decoded.
 /// - `encoded`: A byte slice containing the RLP-encoded block header data.
+/// - `spec`: The `ChainSpec` whose fork schedule selects the decoder.
 ///
 /// # Returns
 ///
@@ -114,67 +296,354 @@ pub fn encode_block_header(
 pub fn decode_block_header(
     block_number: u64,
     encoded: &[u8],
-    chain_id: u64,
+    spec: &ChainSpec,
 ) -> Option<VerifiableBlockHeader> {
-    eras::determine_era_decoder(block_number, chain_id).and_then(|decoder| decoder(encoded).ok())
+    // The timestamp sits at the same list position (index 11) in every era's header layout.
+    let timestamp: u64 = rlp::Rlp::new(encoded).val_at(11).ok()?;
+    let decoder = eras::determine_era_decoder(block_number, timestamp, &spec.forks);
+    decoder(encoded).ok()
+}
+
+/// Reads the `totaldifficulty` column, which holds a hexadecimal or decimal quantity, or nothing when
+/// the total difficulty was not recorded.
+fn total_difficulty(
+    block_header: &VerifiableBlockHeader,
+) -> Result<Option<U256>, VerificationError> {
+    let raw = match block_header.totaldifficulty.as_deref() {
+        Some(raw) if !raw.is_empty() => raw,
+        _ => return Ok(None),
+    };
+    let malformed = |e: &dyn std::fmt::Display| VerificationError::malformed("totaldifficulty", e);
+    let total_difficulty = match raw.strip_prefix("0x") {
+        Some(hex) => U256::from_str_radix(hex, 16).map_err(|e| malformed(&e))?,
+        None => U256::from_dec_str(raw).map_err(|e| malformed(&e))?,
+    };
+    Ok(Some(total_difficulty))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_helpers::create_test_block_header_london; // Adjust import as needed
+    use crate::constants::{DENCUN_END, PECTRA_START, PRAGUE_TIMESTAMP};
+    use crate::test_helpers::{
+        create_test_block_header_london, create_test_block_header_pectra_hoodi,
+        create_test_block_header_pectra_sepolia, create_test_chain, header_columns,
+    }; // Adjust import as needed
+    use eth_rlp_types::BlockHeaderTrait;
 
     #[test]
-    #[ignore]
-    fn test_block_header_encoding_decoding_debug() {
-        // Create the test block header for London era
-        let original_header = create_test_block_header_london();
-        let block_number = original_header.number as u64;
+    fn test_verify_block_hoodi() {
+        let header = create_test_block_header_pectra_hoodi();
+        let block_hash = header.block_hash.clone();
+        let spec = ChainSpec::from_chain_id(CHAIN_ID_HOODI).unwrap();
 
-        println!("Original Header: {:#?}", original_header);
+        assert!(verify_header(&header, &spec).is_ok());
+        assert!(verify_block(header.number as u64, header, &block_hash, &spec).is_ok());
+    }
 
-        // Step 1: Encode the block header
-        let encoded = encode_block_header(block_number, original_header.clone(), CHAIN_ID_MAINNET)
-            .expect("Encoding failed");
+    #[test]
+    fn test_execution_header_matches_era_encoding() {
+        let header = create_test_block_header_pectra_hoodi();
+        let typed = eth_rlp_types::ExecutionHeader::try_from(&header).unwrap();
 
-        println!("Encoded Bytes: {:?}", encoded);
+        assert_eq!(format!("{:?}", typed.hash()), header.block_hash);
+        assert_eq!(
+            Some(rlp::encode(&typed).to_vec()),
+            encode_block_header(header.number as u64, header, &ChainSpec::hoodi())
+        );
+    }
 
-        // Debug: Reprint encoded bytes as hex
-        let encoded_hex: String = encoded.iter().map(|b| format!("{:02x}", b)).collect();
-        println!("Encoded Hex: {}", encoded_hex);
+    #[test]
+    fn test_verification_errors() {
+        let spec = ChainSpec::hoodi();
+        let header = create_test_block_header_pectra_hoodi();
 
-        // Step 2: Decode the block header
-        let decoded_header =
-            decode_block_header(block_number, &encoded, CHAIN_ID_MAINNET).expect("Decoding failed");
+        let mut tampered = header.clone();
+        tampered.gas_used += 1;
+        assert!(matches!(
+            are_blocks_valid(&[tampered], &spec),
+            Err(VerificationError::HashMismatch {
+                block_number: 411443,
+                ..
+            })
+        ));
 
-        println!("Decoded Header: {:#?}", decoded_header);
+        let mut malformed = header.clone();
+        malformed.parent_hash = Some("0x1234".to_string());
+        assert!(matches!(
+            are_blocks_valid(&[malformed], &spec),
+            Err(VerificationError::MalformedField {
+                field: "parent_hash",
+                ..
+            })
+        ));
 
-        // Step 3: Compare individual fields for discrepancies
-        if original_header.parent_hash != decoded_header.parent_hash {
-            println!(
-                "Mismatch in parent_hash:\nOriginal: {}\nDecoded: {}",
-                original_header.parent_hash.clone().unwrap_or_default(),
-                decoded_header.parent_hash.clone().unwrap_or_default()
-            );
-        }
+        let mut undated = header.clone();
+        undated.timestamp = None;
+        assert!(matches!(
+            are_blocks_valid(&[undated], &spec),
+            Err(VerificationError::MalformedField {
+                field: "timestamp",
+                ..
+            })
+        ));
+
+        let mut unsealed = create_test_block_header_london();
+        unsealed.mix_hash = None;
+        assert!(matches!(
+            verify_pow_seal(&unsealed, &mut Ethash::new()),
+            Err(VerificationError::MalformedField {
+                field: "mix_hash",
+                ..
+            })
+        ));
+
+        assert!(matches!(
+            are_blocks_and_chain_valid(&[header.clone(), header], &spec),
+            Err(VerificationError::ParentHashMismatch { index: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_chain_base_fee_validation() {
+        let spec = ChainSpec::hoodi();
+        let options = ChainValidationOptions {
+            check_base_fee: true,
+            ..Default::default()
+        };
+        let mut chain = create_test_chain(3);
+        let headers: Vec<_> = chain
+            .iter()
+            .map(|header| VerifiableBlockHeader::try_from(header).unwrap())
+            .collect();
+        assert_eq!(
+            are_blocks_and_chain_valid_with(&headers, &spec, &options),
+            Ok(())
+        );
+
+        // A fabricated base fee still hashes correctly, but breaks EIP-1559.
+        chain[2].base_fee_per_gas = chain[2].base_fee_per_gas.map(|fee| fee + 1);
+        let headers: Vec<_> = chain
+            .iter()
+            .map(|header| VerifiableBlockHeader::try_from(header).unwrap())
+            .collect();
+        assert!(are_blocks_and_chain_valid(&headers, &spec).is_ok());
+        assert!(matches!(
+            are_blocks_and_chain_valid_with(&headers, &spec, &options),
+            Err(VerificationError::ConsensusRule {
+                index: 2,
+                source: ConsensusError::BaseFeeMismatch { .. },
+            })
+        ));
+    }
+
+    #[test]
+    fn test_chain_gas_limit_validation() {
+        let spec = ChainSpec::hoodi();
+        let options = ChainValidationOptions {
+            check_gas_limit: true,
+            ..Default::default()
+        };
+        let mut chain = create_test_chain(3);
+        chain[1].gas_limit += chain[0].gas_limit / 1024;
+        chain[2].parent_hash = chain[1].hash();
+        let headers: Vec<_> = chain
+            .iter()
+            .map(|header| VerifiableBlockHeader::try_from(header).unwrap())
+            .collect();
+
+        assert!(matches!(
+            are_blocks_and_chain_valid_with(&headers, &spec, &options),
+            Err(VerificationError::ConsensusRule {
+                index: 1,
+                source: ConsensusError::GasLimitOutOfBounds { .. },
+            })
+        ));
+
+        // The first header has no parent in the segment, but must still fit its own gas limit.
+        let mut first = create_test_chain(1);
+        first[0].gas_used = first[0].gas_limit + 1;
+        let headers = [VerifiableBlockHeader::try_from(&first[0]).unwrap()];
         assert_eq!(
-            original_header.parent_hash, decoded_header.parent_hash,
-            "Mismatch in parent_hash"
+            are_blocks_and_chain_valid_with(&headers, &spec, &options),
+            Err(VerificationError::ConsensusRule {
+                index: 0,
+                source: ConsensusError::GasUsedExceedsLimit {
+                    gas_used: first[0].gas_used,
+                    gas_limit: first[0].gas_limit,
+                },
+            })
         );
+    }
 
+    #[test]
+    fn test_chain_header_invariants() {
+        let spec = ChainSpec::hoodi();
+        let options = ChainValidationOptions {
+            check_base_fee: true,
+            check_gas_limit: true,
+            check_blob_gas: true,
+            check_header_invariants: true,
+            ..Default::default()
+        };
+        let mut chain = create_test_chain(3);
+        let headers: Vec<_> = chain
+            .iter()
+            .map(|header| VerifiableBlockHeader::try_from(header).unwrap())
+            .collect();
         assert_eq!(
-            original_header.nonce, decoded_header.nonce,
-            "Mismatch in nonce"
+            are_blocks_and_chain_valid_with(&headers, &spec, &options),
+            Ok(())
         );
 
+        chain[0].nonce = ethereum_types::H64::from_low_u64_be(1);
+        chain[1].parent_hash = chain[0].hash();
+        chain[2].parent_hash = chain[1].hash();
+        let headers: Vec<_> = chain
+            .iter()
+            .map(|header| VerifiableBlockHeader::try_from(header).unwrap())
+            .collect();
         assert_eq!(
-            original_header.base_fee_per_gas, decoded_header.base_fee_per_gas,
-            "Mismatch in base_fee_per_gas"
+            are_blocks_and_chain_valid_with(&headers, &spec, &options),
+            Err(VerificationError::ConsensusRule {
+                index: 0,
+                source: ConsensusError::NonZeroNonce { nonce: 1 },
+            })
         );
+    }
 
+    #[test]
+    fn test_chain_total_difficulty() {
+        let spec = ChainSpec::hoodi();
+        let options = ChainValidationOptions {
+            check_difficulty: true,
+            check_total_difficulty: true,
+            ..Default::default()
+        };
+        let mut headers: Vec<_> = create_test_chain(3)
+            .iter()
+            .map(|header| VerifiableBlockHeader::try_from(header).unwrap())
+            .collect();
+        headers[0].totaldifficulty = Some("0x1000".to_string());
+        headers[1].totaldifficulty = None;
+        headers[2].totaldifficulty = Some("4096".to_string());
         assert_eq!(
-            original_header, decoded_header,
-            "The original and decoded headers do not match"
+            are_blocks_and_chain_valid_with(&headers, &spec, &options),
+            Ok(())
         );
+
+        headers[1].totaldifficulty = Some("4096".to_string());
+        headers[2].totaldifficulty = Some("4097".to_string());
+        assert_eq!(
+            are_blocks_and_chain_valid_with(&headers, &spec, &options),
+            Err(VerificationError::ConsensusRule {
+                index: 2,
+                source: ConsensusError::TotalDifficultyMismatch {
+                    expected: U256::from(4096),
+                    found: U256::from(4097),
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_chain_continuity_and_gaps() {
+        let spec = ChainSpec::hoodi();
+        let mut headers: Vec<_> = create_test_chain(5)
+            .iter()
+            .map(|header| VerifiableBlockHeader::try_from(header).unwrap())
+            .collect();
+        headers.remove(2);
+
+        let strict = ChainValidationOptions {
+            check_continuity: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            are_blocks_and_chain_valid_with(&headers, &spec, &strict),
+            Err(VerificationError::ContinuityBreak(ContinuityBreak::Gap {
+                index: 2,
+                ..
+            }))
+        ));
+
+        let segments = ChainValidationOptions {
+            check_continuity: true,
+            check_base_fee: true,
+            allow_gaps: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            are_blocks_and_chain_valid_with(&headers, &spec, &segments),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_mainnet_pectra_dispatch() {
+        let mut header = create_test_block_header_pectra_sepolia();
+
+        header.timestamp = Some(format!("{:#x}", PRAGUE_TIMESTAMP - 12));
+        let dencun = encode_block_header(DENCUN_END, header.clone(), &ChainSpec::mainnet())
+            .expect("Dencun encoder missing");
+        assert_eq!(rlp::Rlp::new(&dencun).item_count().unwrap(), 20);
+
+        header.timestamp = Some(format!("{:#x}", PRAGUE_TIMESTAMP));
+        let pectra = encode_block_header(PECTRA_START, header, &ChainSpec::mainnet())
+            .expect("Pectra encoder missing");
+        assert_eq!(rlp::Rlp::new(&pectra).item_count().unwrap(), 21);
+
+        assert!(decode_block_header(PECTRA_START, &pectra, &ChainSpec::mainnet()).is_some());
+    }
+
+    #[test]
+    fn test_decode_block_header_rejects_oversized_quantities() {
+        let db_header = create_test_block_header_pectra_hoodi();
+        let block_number = db_header.number as u64;
+        let spec = ChainSpec::hoodi();
+        let mut header = eras::BlockHeaderPectra::try_from(&db_header).unwrap();
+
+        header.number = U256::from(i64::MAX) + 1;
+        assert_eq!(
+            decode_block_header(block_number, &header.rlp_encode(), &spec),
+            None
+        );
+        assert!(matches!(
+            header.into_verifiable(),
+            Err(VerificationError::MalformedField {
+                field: "number",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_block_header_encoding_decoding_debug() {
+        for (original_header, spec) in [
+            (create_test_block_header_london(), ChainSpec::mainnet()),
+            (
+                create_test_block_header_pectra_sepolia(),
+                ChainSpec::sepolia(),
+            ),
+            (create_test_block_header_pectra_hoodi(), ChainSpec::hoodi()),
+        ] {
+            let block_number = original_header.number as u64;
+
+            // Step 1: Encode the block header
+            let encoded = encode_block_header(block_number, original_header.clone(), &spec)
+                .expect("Encoding failed");
+
+            // Step 2: Decode the block header
+            let decoded_header =
+                decode_block_header(block_number, &encoded, &spec).expect("Decoding failed");
+
+            // Step 3: Only the columns the header does not encode are lost on the way
+            assert_eq!(
+                header_columns(&original_header),
+                decoded_header,
+                "The original and decoded headers do not match"
+            );
+            assert!(verify_header(&decoded_header, &spec).is_ok());
+        }
     }
 }
@@ -0,0 +1,190 @@
+use crate::chain_spec::ChainSpec;
+use crate::consensus::{ChainValidationOptions, ConsensusError};
+use crate::error::VerificationError;
+use eth_rlp_types::fields;
+use eth_rlp_types::BlockHeader as VerifiableBlockHeader;
+use ethereum_types::U256;
+
+/// Proves that a sequence of headers contains a valid transition from proof-of-work to proof-of-stake.
+///
+/// The headers must include the parent of the terminal proof-of-work block, the terminal block itself and
+/// the first proof-of-stake block, in order. They are first verified as a chain segment, including the
+/// accumulation of their `totaldifficulty`, so the first proof-of-stake block is proven to follow the
+/// terminal block. The terminal block must then have reached the chain's terminal total difficulty while
+/// its parent had not.
+///
+/// # Arguments
+///
+/// - `block_headers`: Consecutive headers around the Merge, e.g. `LONDON_END - 1..=PARIS_START`.
+/// - `spec`: The `ChainSpec` of the chain, which provides the terminal total difficulty.
+///
+/// # Returns
+///
+/// The number of the terminal proof-of-work block, or a `VerificationError`:
+/// `MergeTransitionNotFound` if the chain has no terminal total difficulty or the headers do not contain
+/// the three blocks above, `MalformedField` if one of them has no `difficulty` or `totaldifficulty`,
+/// `MergeBlockMismatch` if the first proof-of-stake block is not the chain's scheduled Paris block, and
+/// `ConsensusRule` with the index of the offending header if the terminal total difficulty was not
+/// crossed exactly at the terminal block.
+pub fn validate_merge_transition(
+    block_headers: &[VerifiableBlockHeader],
+    spec: &ChainSpec,
+) -> Result<u64, VerificationError> {
+    let terminal_total_difficulty = spec
+        .terminal_total_difficulty
+        .ok_or(VerificationError::MergeTransitionNotFound)?;
+
+    let options = ChainValidationOptions {
+        check_total_difficulty: true,
+        ..Default::default()
+    };
+    crate::are_blocks_and_chain_valid_with(block_headers, spec, &options)?;
+
+    let mut proof_of_stake = Vec::with_capacity(block_headers.len());
+    for header in block_headers {
+        proof_of_stake.push(is_zero_difficulty(header)?);
+    }
+    let first_pos = (2..block_headers.len())
+        .find(|&i| proof_of_stake[i] && !proof_of_stake[i - 1] && !proof_of_stake[i - 2])
+        .ok_or(VerificationError::MergeTransitionNotFound)?;
+    let terminal = first_pos - 1;
+
+    let first_pos_number = block_headers[first_pos].number as u64;
+    if let Some(paris_block) = spec.forks.paris_block {
+        if paris_block != first_pos_number {
+            return Err(VerificationError::MergeBlockMismatch {
+                expected: paris_block,
+                found: first_pos_number,
+            });
+        }
+    }
+
+    let terminal_reached = |index: usize| -> Result<(bool, U256), VerificationError> {
+        let total_difficulty = crate::total_difficulty(&block_headers[index])?
+            .ok_or_else(|| VerificationError::malformed("totaldifficulty", "missing value"))?;
+        Ok((
+            total_difficulty >= terminal_total_difficulty,
+            total_difficulty,
+        ))
+    };
+
+    let (reached, total_difficulty) = terminal_reached(terminal - 1)?;
+    if reached {
+        return Err(VerificationError::ConsensusRule {
+            index: terminal - 1,
+            source: ConsensusError::TerminalTotalDifficultyAlreadyReached {
+                total_difficulty,
+                terminal_total_difficulty,
+            },
+        });
+    }
+    let (reached, total_difficulty) = terminal_reached(terminal)?;
+    if !reached {
+        return Err(VerificationError::ConsensusRule {
+            index: terminal,
+            source: ConsensusError::TerminalTotalDifficultyNotReached {
+                total_difficulty,
+                terminal_total_difficulty,
+            },
+        });
+    }
+
+    Ok(block_headers[terminal].number as u64)
+}
+
+fn is_zero_difficulty(header: &VerifiableBlockHeader) -> Result<bool, VerificationError> {
+    Ok(fields::u256("difficulty", &header.difficulty)?.is_zero())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eras::ForkSchedule;
+    use eth_rlp_types::ExecutionHeader;
+
+    fn transition(total_difficulties: [u64; 3]) -> (ChainSpec, Vec<VerifiableBlockHeader>) {
+        let spec = ChainSpec {
+            chain_id: 1337,
+            genesis_hash: None,
+            forks: ForkSchedule {
+                london_block: Some(0),
+                paris_block: Some(102),
+                ..Default::default()
+            },
+            terminal_total_difficulty: Some(U256::from(2_000)),
+        };
+
+        let mut headers: Vec<VerifiableBlockHeader> = Vec::new();
+        let mut parent_hash = Default::default();
+        for (i, difficulty) in [1_000u64, 1_000, 0].into_iter().enumerate() {
+            let header = ExecutionHeader {
+                parent_hash,
+                number: 100 + i as u64,
+                timestamp: 1_000 + 12 * i as u64,
+                difficulty: U256::from(difficulty),
+                gas_limit: 30_000_000,
+                base_fee_per_gas: Some(1_000_000_000),
+                ..Default::default()
+            };
+            parent_hash = header.hash();
+            let mut header = VerifiableBlockHeader::try_from(header).unwrap();
+            header.totaldifficulty = Some(format!("{:#x}", total_difficulties[i]));
+            headers.push(header);
+        }
+        (spec, headers)
+    }
+
+    #[test]
+    fn test_validate_merge_transition() {
+        let (spec, headers) = transition([1_500, 2_500, 2_500]);
+        assert_eq!(validate_merge_transition(&headers, &spec), Ok(101));
+        assert_eq!(
+            validate_merge_transition(&headers[1..], &spec),
+            Err(VerificationError::MergeTransitionNotFound)
+        );
+
+        let (spec, headers) = transition([500, 1_500, 1_500]);
+        assert_eq!(
+            validate_merge_transition(&headers, &spec),
+            Err(VerificationError::ConsensusRule {
+                index: 1,
+                source: ConsensusError::TerminalTotalDifficultyNotReached {
+                    total_difficulty: U256::from(1_500),
+                    terminal_total_difficulty: U256::from(2_000),
+                },
+            })
+        );
+
+        let (spec, headers) = transition([2_000, 3_000, 3_000]);
+        assert!(matches!(
+            validate_merge_transition(&headers, &spec),
+            Err(VerificationError::ConsensusRule {
+                index: 0,
+                source: ConsensusError::TerminalTotalDifficultyAlreadyReached { .. },
+            })
+        ));
+    }
+
+    #[test]
+    fn test_merge_block_and_difficulty_are_checked() {
+        let (mut spec, headers) = transition([1_500, 2_500, 2_500]);
+        spec.forks.paris_block = Some(103);
+        assert_eq!(
+            validate_merge_transition(&headers, &spec),
+            Err(VerificationError::MergeBlockMismatch {
+                expected: 103,
+                found: 102,
+            })
+        );
+
+        let (spec, mut headers) = transition([1_500, 2_500, 2_500]);
+        headers[2].difficulty = None;
+        assert!(matches!(
+            validate_merge_transition(&headers, &spec),
+            Err(VerificationError::MalformedField {
+                field: "difficulty",
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,210 @@
+use crate::chain_spec::ChainSpec;
+use crate::consensus::ConsensusError;
+use crate::eras::{self, Era};
+use crate::error::VerificationError;
+use crate::hash::keccak256;
+use eth_rlp_types::fields;
+use eth_rlp_types::BlockHeader as VerifiableBlockHeader;
+use ethereum_types::H256;
+use rlp::RlpStream;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+/// The largest number of ommers a proof-of-work block may include.
+pub const MAX_OMMERS: usize = 2;
+
+/// The largest distance, in blocks, between a block and the ommers it includes.
+pub const MAX_OMMER_DEPTH: u64 = 6;
+
+/// Computes the `ommers_hash` of a list of ommer headers: the Keccak256 hash of the RLP list of their
+/// encodings, each encoded with the layout of its own era.
+///
+/// # Returns
+///
+/// The hash the including block should carry as its `ommers_hash`, or a `VerificationError` if one of
+/// the ommers cannot be encoded.
+pub fn ommers_hash(
+    ommers: &[VerifiableBlockHeader],
+    spec: &ChainSpec,
+) -> Result<H256, VerificationError> {
+    let mut stream = RlpStream::new_list(ommers.len());
+    for ommer in ommers {
+        let timestamp = eras::header_timestamp(ommer)?;
+        let encoder = eras::determine_era_encoder(ommer.number as u64, timestamp, &spec.forks);
+        stream.append_raw(&encoder(ommer)?, 1);
+    }
+    Ok(keccak256(&stream.out()))
+}
+
+/// Verifies the ommers (uncles) included by a block against its `ommers_hash`.
+///
+/// The block and each ommer must hash to their own `block_hash`, and the list as a whole must hash to the block's
+/// `sha3_uncles`. On top of that, the block may include at most `MAX_OMMERS` distinct ommers (none after
+/// the Merge), each between one and `MAX_OMMER_DEPTH` blocks older than the block itself, and none of
+/// them may be the block's parent.
+///
+/// # Arguments
+///
+/// - `block_header`: The block including the ommers.
+/// - `ommers`: The ommer headers, in the order the block lists them.
+/// - `spec`: The `ChainSpec` of the chain the block belongs to.
+///
+/// # Returns
+///
+/// `Ok(())` if the ommers are valid, a `VerificationError` from verifying the block's or an ommer's own
+/// hash, or
+/// `VerificationError::InvalidOmmers` naming the rule that is broken.
+pub fn verify_ommers(
+    block_header: &VerifiableBlockHeader,
+    ommers: &[VerifiableBlockHeader],
+    spec: &ChainSpec,
+) -> Result<(), VerificationError> {
+    let block_number = block_header.number as u64;
+    crate::verify_block(
+        block_number,
+        block_header.clone(),
+        &block_header.block_hash,
+        spec,
+    )?;
+    let invalid = |source: ConsensusError| VerificationError::InvalidOmmers {
+        block_number,
+        source,
+    };
+
+    let era = spec
+        .forks
+        .era_at(block_number, eras::header_timestamp(block_header)?);
+    let max = if era >= Era::Paris { 0 } else { MAX_OMMERS };
+    if ommers.len() > max {
+        return Err(invalid(ConsensusError::TooManyOmmers {
+            count: ommers.len(),
+            max,
+        }));
+    }
+
+    let parent_hash = block_header.parent_hash.as_deref().unwrap_or_default();
+    let mut seen = HashSet::new();
+    for ommer in ommers {
+        crate::verify_block(ommer.number as u64, ommer.clone(), &ommer.block_hash, spec)?;
+        let hash = H256::from_str(&ommer.block_hash)
+            .map_err(|e| VerificationError::malformed("block_hash", e))?;
+
+        if !seen.insert(hash) {
+            return Err(invalid(ConsensusError::DuplicateOmmer { hash }));
+        }
+        if ommer.block_hash == parent_hash {
+            return Err(invalid(ConsensusError::OmmerIsAncestor { hash }));
+        }
+        let ommer_number = ommer.number as u64;
+        let depth = block_number.saturating_sub(ommer_number);
+        if ommer_number >= block_number || depth > MAX_OMMER_DEPTH {
+            return Err(invalid(ConsensusError::OmmerDepthOutOfRange {
+                block_number,
+                ommer_number,
+            }));
+        }
+    }
+
+    let expected = fields::h256("sha3_uncles", &block_header.sha3_uncles)?;
+    let computed = ommers_hash(ommers, spec)?;
+    if computed != expected {
+        return Err(invalid(ConsensusError::OmmersHashMismatch {
+            expected,
+            computed,
+        }));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eras::ForkSchedule;
+    use eth_rlp_types::ExecutionHeader;
+    use ethereum_types::U256;
+
+    fn pow_header(number: u64, extra_data: &[u8]) -> ExecutionHeader {
+        ExecutionHeader {
+            number,
+            timestamp: 1_000 + number * 13,
+            difficulty: U256::from(131_072),
+            gas_limit: 30_000_000,
+            extra_data: extra_data.to_vec(),
+            base_fee_per_gas: Some(1_000_000_000),
+            ..Default::default()
+        }
+    }
+
+    fn block_with_ommers(ommers: &[ExecutionHeader]) -> VerifiableBlockHeader {
+        let encoded: Vec<_> = ommers.iter().map(rlp::encode).collect();
+        let mut stream = RlpStream::new_list(encoded.len());
+        for ommer in &encoded {
+            stream.append_raw(ommer, 1);
+        }
+        let mut block = pow_header(100, b"block");
+        block.ommers_hash = keccak256(&stream.out());
+        VerifiableBlockHeader::try_from(block).unwrap()
+    }
+
+    #[test]
+    fn test_verify_ommers() {
+        let spec = ChainSpec {
+            chain_id: 1337,
+            genesis_hash: None,
+            forks: ForkSchedule {
+                london_block: Some(0),
+                ..Default::default()
+            },
+            terminal_total_difficulty: None,
+        };
+        assert_eq!(
+            ommers_hash(&[], &spec),
+            Ok(H256::from_str(crate::constants::EMPTY_OMMERS_HASH).unwrap())
+        );
+
+        let ommers = [pow_header(99, b"uncle"), pow_header(94, b"uncle")];
+        let block = block_with_ommers(&ommers);
+        let verifiable: Vec<_> = ommers
+            .iter()
+            .map(|header| VerifiableBlockHeader::try_from(header).unwrap())
+            .collect();
+        assert_eq!(verify_ommers(&block, &verifiable, &spec), Ok(()));
+
+        assert!(matches!(
+            verify_ommers(&block, &verifiable[..1], &spec),
+            Err(VerificationError::InvalidOmmers {
+                source: ConsensusError::OmmersHashMismatch { .. },
+                ..
+            })
+        ));
+
+        let mut forged = block.clone();
+        forged.block_hash = format!("{:?}", H256::repeat_byte(0xab));
+        assert!(matches!(
+            verify_ommers(&forged, &verifiable, &spec),
+            Err(VerificationError::HashMismatch { .. })
+        ));
+
+        let duplicated = [verifiable[0].clone(), verifiable[0].clone()];
+        assert!(matches!(
+            verify_ommers(&block, &duplicated, &spec),
+            Err(VerificationError::InvalidOmmers {
+                source: ConsensusError::DuplicateOmmer { .. },
+                ..
+            })
+        ));
+
+        let too_old = [VerifiableBlockHeader::try_from(pow_header(93, b"uncle")).unwrap()];
+        assert_eq!(
+            verify_ommers(&block, &too_old, &spec),
+            Err(VerificationError::InvalidOmmers {
+                block_number: 100,
+                source: ConsensusError::OmmerDepthOutOfRange {
+                    block_number: 100,
+                    ommer_number: 93,
+                },
+            })
+        );
+    }
+}
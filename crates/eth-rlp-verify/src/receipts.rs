@@ -0,0 +1,245 @@
+use crate::bloom::LogsBloom;
+use crate::chain_spec::ChainSpec;
+use crate::eras::EraHeader;
+use crate::error::VerificationError;
+use crate::transactions::max_transaction_type;
+use crate::trie::ordered_trie_root;
+use eth_rlp_types::BlockHeader as VerifiableBlockHeader;
+use ethereum_types::{H160, H256};
+use rlp::{Encodable, RlpStream};
+
+/// A log emitted by a transaction.
+///
+/// # Fields
+///
+/// - `address`: The contract that emitted the log.
+/// - `topics`: The indexed topics of the log, at most four.
+/// - `data`: The non-indexed data of the log.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Log {
+    pub address: H160,
+    pub topics: Vec<H256>,
+    pub data: Vec<u8>,
+}
+
+impl Encodable for Log {
+    fn rlp_append(&self, stream: &mut RlpStream) {
+        stream.begin_list(3);
+        stream.append(&self.address);
+        stream.append_list(&self.topics);
+        stream.append(&self.data);
+    }
+}
+
+/// The outcome a receipt records for its transaction.
+///
+/// # Variants
+///
+/// - `PostState`: The state root after the transaction, recorded before Byzantium.
+/// - `Status`: Whether the transaction succeeded, recorded since Byzantium (EIP-658).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReceiptOutcome {
+    PostState(H256),
+    Status(bool),
+}
+
+/// The receipt of a transaction, as committed to by a block's `receipts_root`.
+///
+/// # Fields
+///
+/// - `tx_type`: The EIP-2718 type of the transaction, 0 for legacy transactions.
+/// - `outcome`: The post-transaction state root or the success status.
+/// - `cumulative_gas_used`: The gas used by this and all preceding transactions of the block.
+/// - `logs_bloom`: The bloom filter of the logs, see [`LogsBloom::from_logs`].
+/// - `logs`: The logs emitted by the transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Receipt {
+    pub tx_type: u8,
+    pub outcome: ReceiptOutcome,
+    pub cumulative_gas_used: u64,
+    pub logs_bloom: LogsBloom,
+    pub logs: Vec<Log>,
+}
+
+impl Receipt {
+    /// Encodes the receipt as an EIP-2718 envelope: the RLP list of its fields, preceded by the type byte
+    /// unless it is the receipt of a legacy transaction.
+    pub fn encoded_2718(&self) -> Vec<u8> {
+        let mut stream = RlpStream::new_list(4);
+        match self.outcome {
+            ReceiptOutcome::PostState(root) => stream.append(&root),
+            ReceiptOutcome::Status(success) => stream.append(&u8::from(success)),
+        };
+        stream.append(&self.cumulative_gas_used);
+        stream.append(&self.logs_bloom);
+        stream.append_list(&self.logs);
+
+        let mut encoded = Vec::new();
+        if self.tx_type != 0 {
+            encoded.push(self.tx_type);
+        }
+        encoded.extend_from_slice(&stream.out());
+        encoded
+    }
+}
+
+/// Computes the receipts root of a block: the root of the trie that maps the RLP-encoded index of each
+/// receipt to its EIP-2718 encoding.
+pub fn receipts_root(receipts: &[Receipt]) -> H256 {
+    let encoded: Vec<_> = receipts.iter().map(Receipt::encoded_2718).collect();
+    ordered_trie_root(&encoded)
+}
+
+/// Verifies that a list of receipts belongs to a block header.
+///
+/// The header is verified against its own hash first, so a successful check ties the receipts to that
+/// hash. Each receipt must be of a transaction type the header's era supports and record a status from
+/// Byzantium onwards and a post-state root before it. The receipts root computed from them must equal
+/// the `receipts_root` of the era header.
+///
+/// # Arguments
+///
+/// - `block_header`: The header of the block.
+/// - `receipts`: The receipts of the block's transactions, in block order.
+/// - `spec`: The `ChainSpec` of the chain the block belongs to.
+///
+/// # Returns
+///
+/// `Ok(())` if the receipts belong to the header, `VerificationError::RootMismatch` if the roots differ,
+/// or another `VerificationError` if the header or a receipt is invalid.
+pub fn verify_receipts_root(
+    block_header: &VerifiableBlockHeader,
+    receipts: &[Receipt],
+    spec: &ChainSpec,
+) -> Result<(), VerificationError> {
+    let block_number = block_header.number as u64;
+    crate::verify_block(
+        block_number,
+        block_header.clone(),
+        &block_header.block_hash,
+        spec,
+    )?;
+    let header = EraHeader::from_header(block_header, &spec.forks)?;
+
+    let max_type = max_transaction_type(header.era(), block_number, &spec.forks);
+    let byzantium = spec
+        .forks
+        .byzantium_block
+        .is_some_and(|b| block_number >= b);
+    for (index, receipt) in receipts.iter().enumerate() {
+        if receipt.tx_type > max_type {
+            return Err(VerificationError::malformed(
+                "receipts",
+                format!(
+                    "receipt {} has type {}, which the {:?} era does not support",
+                    index,
+                    receipt.tx_type,
+                    header.era()
+                ),
+            ));
+        }
+        if matches!(receipt.outcome, ReceiptOutcome::Status(_)) != byzantium {
+            return Err(VerificationError::malformed(
+                "receipts",
+                format!(
+                    "receipt {} must record a {}",
+                    index,
+                    if byzantium {
+                        "status"
+                    } else {
+                        "post-state root"
+                    }
+                ),
+            ));
+        }
+    }
+
+    let computed = receipts_root(receipts);
+    if computed != header.receipts_root() {
+        return Err(VerificationError::RootMismatch {
+            block_number,
+            field: "receipts_root",
+            expected: header.receipts_root(),
+            computed,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bloom::verify_logs_bloom;
+    use crate::test_helpers::{
+        create_test_block_header_pectra_hoodi, create_test_block_receipts_pectra_hoodi,
+        create_test_chain,
+    };
+    use std::str::FromStr;
+
+    fn receipt() -> Receipt {
+        let mut logs_bloom = LogsBloom::default();
+        logs_bloom.0[255] = 1;
+        Receipt {
+            tx_type: 1,
+            outcome: ReceiptOutcome::Status(true),
+            cumulative_gas_used: 102_068,
+            logs_bloom,
+            logs: vec![Log::default()],
+        }
+    }
+
+    #[test]
+    fn test_receipts_root() {
+        // The receipt and root of alloy's `check_receipt_root_optimism` test.
+        assert_eq!(
+            receipts_root(&[receipt()]),
+            H256::from_str("0xfe70ae4a136d98944951b2123859698d59ad251a381abc9960fa81cae3d0d4a0")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verify_receipts_root_hoodi() {
+        let block_header = create_test_block_header_pectra_hoodi();
+        let receipts = create_test_block_receipts_pectra_hoodi();
+        assert_eq!(
+            verify_receipts_root(&block_header, &receipts, &ChainSpec::hoodi()),
+            Ok(())
+        );
+        assert_eq!(
+            verify_logs_bloom(&block_header, &receipts, &ChainSpec::hoodi()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_verify_receipts_root() {
+        let spec = ChainSpec::hoodi();
+        let receipts = [receipt(), receipt()];
+        let mut header = create_test_chain(1).remove(0);
+        header.receipts_root = receipts_root(&receipts);
+        let block_header = VerifiableBlockHeader::try_from(&header).unwrap();
+
+        assert_eq!(
+            verify_receipts_root(&block_header, &receipts, &spec),
+            Ok(())
+        );
+        assert!(matches!(
+            verify_receipts_root(&block_header, &receipts[..1], &spec),
+            Err(VerificationError::RootMismatch {
+                field: "receipts_root",
+                ..
+            })
+        ));
+
+        let mut pre_byzantium = receipt();
+        pre_byzantium.outcome = ReceiptOutcome::PostState(H256::zero());
+        assert!(matches!(
+            verify_receipts_root(&block_header, &[pre_byzantium], &spec),
+            Err(VerificationError::MalformedField {
+                field: "receipts",
+                ..
+            })
+        ));
+    }
+}
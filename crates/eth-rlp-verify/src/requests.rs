@@ -0,0 +1,304 @@
+use crate::chain_spec::ChainSpec;
+use crate::eras::EraHeader;
+use crate::error::VerificationError;
+use eth_rlp_types::BlockHeader as VerifiableBlockHeader;
+use ethereum_types::{H160, H256};
+use sha2::{Digest, Sha256};
+
+/// The request type of deposits (EIP-6110).
+pub const DEPOSIT_REQUEST_TYPE: u8 = 0x00;
+/// The request type of withdrawal requests triggered from the execution layer (EIP-7002).
+pub const WITHDRAWAL_REQUEST_TYPE: u8 = 0x01;
+/// The request type of consolidation requests (EIP-7251).
+pub const CONSOLIDATION_REQUEST_TYPE: u8 = 0x02;
+
+/// The `requests_hash` of a block without any requests: the SHA-256 hash of the empty string.
+pub const EMPTY_REQUESTS_HASH: &str =
+    "0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+/// A validator deposit made through the deposit contract (EIP-6110), encoded in 192 bytes.
+///
+/// # Fields
+///
+/// - `pubkey`: The BLS public key of the validator.
+/// - `withdrawal_credentials`: The withdrawal credentials of the validator.
+/// - `amount`: The deposited amount, in gwei.
+/// - `signature`: The BLS signature over the deposit message.
+/// - `index`: The index of the deposit in the deposit contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepositRequest {
+    pub pubkey: [u8; 48],
+    pub withdrawal_credentials: H256,
+    pub amount: u64,
+    pub signature: [u8; 96],
+    pub index: u64,
+}
+
+impl DepositRequest {
+    /// Encodes the deposit as `pubkey ++ withdrawal_credentials ++ amount ++ signature ++ index`, with the
+    /// amount and index in little-endian byte order as the deposit contract emits them.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(192);
+        encoded.extend_from_slice(&self.pubkey);
+        encoded.extend_from_slice(self.withdrawal_credentials.as_bytes());
+        encoded.extend_from_slice(&self.amount.to_le_bytes());
+        encoded.extend_from_slice(&self.signature);
+        encoded.extend_from_slice(&self.index.to_le_bytes());
+        encoded
+    }
+}
+
+/// A withdrawal or exit triggered from the execution layer (EIP-7002), encoded in 76 bytes.
+///
+/// # Fields
+///
+/// - `source_address`: The address that sent the request, which must be the validator's withdrawal address.
+/// - `validator_pubkey`: The BLS public key of the validator.
+/// - `amount`: The amount to withdraw, in gwei; zero requests a full exit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawalRequest {
+    pub source_address: H160,
+    pub validator_pubkey: [u8; 48],
+    pub amount: u64,
+}
+
+impl WithdrawalRequest {
+    /// Encodes the request as `source_address ++ validator_pubkey ++ amount`, with the amount in
+    /// big-endian byte order as the system contract returns it.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(76);
+        encoded.extend_from_slice(self.source_address.as_bytes());
+        encoded.extend_from_slice(&self.validator_pubkey);
+        encoded.extend_from_slice(&self.amount.to_be_bytes());
+        encoded
+    }
+}
+
+/// A request to consolidate one validator's balance into another (EIP-7251), encoded in 116 bytes.
+///
+/// # Fields
+///
+/// - `source_address`: The address that sent the request.
+/// - `source_pubkey`: The BLS public key of the validator being consolidated.
+/// - `target_pubkey`: The BLS public key of the validator receiving the balance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsolidationRequest {
+    pub source_address: H160,
+    pub source_pubkey: [u8; 48],
+    pub target_pubkey: [u8; 48],
+}
+
+impl ConsolidationRequest {
+    /// Encodes the request as `source_address ++ source_pubkey ++ target_pubkey`.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(116);
+        encoded.extend_from_slice(self.source_address.as_bytes());
+        encoded.extend_from_slice(&self.source_pubkey);
+        encoded.extend_from_slice(&self.target_pubkey);
+        encoded
+    }
+}
+
+/// The execution layer requests of a block, grouped by type.
+///
+/// # Fields
+///
+/// - `deposits`: The deposits, in the order of their deposit contract logs.
+/// - `withdrawals`: The withdrawal requests dequeued by the withdrawal request contract.
+/// - `consolidations`: The consolidation requests dequeued by the consolidation request contract.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecutionRequests {
+    pub deposits: Vec<DepositRequest>,
+    pub withdrawals: Vec<WithdrawalRequest>,
+    pub consolidations: Vec<ConsolidationRequest>,
+}
+
+impl ExecutionRequests {
+    /// Encodes the requests as EIP-7685 typed requests: one `request_type ++ request_data` entry per type,
+    /// in ascending type order, where `request_data` concatenates every request of that type. Types
+    /// without any request are left out.
+    pub fn encode(&self) -> Vec<Vec<u8>> {
+        let groups: [(u8, Vec<u8>); 3] = [
+            (
+                DEPOSIT_REQUEST_TYPE,
+                self.deposits
+                    .iter()
+                    .flat_map(DepositRequest::encode)
+                    .collect(),
+            ),
+            (
+                WITHDRAWAL_REQUEST_TYPE,
+                self.withdrawals
+                    .iter()
+                    .flat_map(WithdrawalRequest::encode)
+                    .collect(),
+            ),
+            (
+                CONSOLIDATION_REQUEST_TYPE,
+                self.consolidations
+                    .iter()
+                    .flat_map(ConsolidationRequest::encode)
+                    .collect(),
+            ),
+        ];
+        groups
+            .into_iter()
+            .filter(|(_, data)| !data.is_empty())
+            .map(|(ty, data)| [vec![ty], data].concat())
+            .collect()
+    }
+
+    /// Computes the `requests_hash` committing to these requests.
+    pub fn requests_hash(&self) -> H256 {
+        requests_hash(&self.encode())
+    }
+}
+
+/// Computes the EIP-7685 `requests_hash` of a list of typed requests.
+///
+/// Each entry is a `request_type` byte followed by its `request_data`. Entries without any data are
+/// skipped; the hash of every remaining entry is then hashed again as one byte string:
+/// `sha256(sha256(requests[0]) ++ sha256(requests[1]) ++ ...)`.
+///
+/// # Arguments
+///
+/// - `requests`: The typed requests in ascending `request_type` order, as carried by the Engine API.
+pub fn requests_hash<T: AsRef<[u8]>>(requests: &[T]) -> H256 {
+    let mut outer = Sha256::new();
+    for request in requests {
+        let request = request.as_ref();
+        if request.len() > 1 {
+            outer.update(Sha256::digest(request));
+        }
+    }
+    H256::from_slice(&outer.finalize())
+}
+
+/// Verifies that a block's execution layer requests match the `requests_hash` of its Pectra header.
+///
+/// The header is verified against its own hash first, so a successful check ties the requests to that
+/// hash.
+///
+/// # Arguments
+///
+/// - `block_header`: The header of the block.
+/// - `requests`: The execution layer requests of the block.
+/// - `spec`: The `ChainSpec` of the chain the block belongs to.
+///
+/// # Returns
+///
+/// `Ok(())` if the requests belong to the header, `VerificationError::RootMismatch` if the hashes differ,
+/// `VerificationError::MalformedField` if the header predates Pectra or lacks its `request_hash`, or
+/// another `VerificationError` if the header is invalid.
+pub fn verify_requests_hash(
+    block_header: &VerifiableBlockHeader,
+    requests: &ExecutionRequests,
+    spec: &ChainSpec,
+) -> Result<(), VerificationError> {
+    let block_number = block_header.number as u64;
+    crate::verify_block(
+        block_number,
+        block_header.clone(),
+        &block_header.block_hash,
+        spec,
+    )?;
+    let header = EraHeader::from_header(block_header, &spec.forks)?;
+    let expected = header.requests_hash().ok_or_else(|| {
+        VerificationError::malformed(
+            "request_hash",
+            format!("the {:?} era has no execution layer requests", header.era()),
+        )
+    })?;
+
+    let computed = requests.requests_hash();
+    if computed != expected {
+        return Err(VerificationError::RootMismatch {
+            block_number,
+            field: "request_hash",
+            expected,
+            computed,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{
+        create_test_block_header_pectra_hoodi, create_test_block_header_pectra_sepolia,
+        create_test_chain,
+    };
+    use std::str::FromStr;
+
+    #[test]
+    fn test_requests_hash() {
+        let empty: [Vec<u8>; 0] = [];
+        assert_eq!(
+            requests_hash(&empty),
+            H256::from_str(EMPTY_REQUESTS_HASH).unwrap()
+        );
+        // The requests and hash of alloy's `test_consistent_requests_hash`.
+        assert_eq!(
+            requests_hash(&[vec![0x00, 0x0a, 0x0b, 0x0c], vec![0x01, 0x0d, 0x0e, 0x0f]]),
+            H256::from_str("0xbe3a57667b9bb9e0275019c0faf0f415fdc8385a408fd03e13a5c50615e3530c")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verify_requests_hash_real_blocks() {
+        // Neither block carried a deposit, withdrawal or consolidation request.
+        for (block_header, spec) in [
+            (
+                create_test_block_header_pectra_sepolia(),
+                ChainSpec::sepolia(),
+            ),
+            (create_test_block_header_pectra_hoodi(), ChainSpec::hoodi()),
+        ] {
+            assert_eq!(
+                verify_requests_hash(&block_header, &ExecutionRequests::default(), &spec),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_requests_hash() {
+        let spec = ChainSpec::hoodi();
+        let requests = ExecutionRequests {
+            deposits: vec![DepositRequest {
+                pubkey: [0x01; 48],
+                withdrawal_credentials: H256::repeat_byte(0x02),
+                amount: 32_000_000_000,
+                signature: [0x03; 96],
+                index: 7,
+            }],
+            withdrawals: vec![],
+            consolidations: vec![ConsolidationRequest {
+                source_address: H160::repeat_byte(0x04),
+                source_pubkey: [0x05; 48],
+                target_pubkey: [0x06; 48],
+            }],
+        };
+        let encoded = requests.encode();
+        assert_eq!(encoded.len(), 2);
+        assert_eq!(encoded[0].len(), 1 + 192);
+        assert_eq!(encoded[1].len(), 1 + 116);
+
+        let mut header = create_test_chain(1).remove(0);
+        header.requests_hash = Some(requests.requests_hash());
+        let block_header = VerifiableBlockHeader::try_from(&header).unwrap();
+        assert_eq!(
+            verify_requests_hash(&block_header, &requests, &spec),
+            Ok(())
+        );
+        assert!(matches!(
+            verify_requests_hash(&block_header, &ExecutionRequests::default(), &spec),
+            Err(VerificationError::RootMismatch {
+                field: "request_hash",
+                ..
+            })
+        ));
+    }
+}
@@ -1,6 +1,58 @@
-use eth_rlp_types::BlockHeader;
+use crate::consensus::calc_next_base_fee;
+use crate::constants::EMPTY_OMMERS_HASH;
+use crate::eras::ForkSchedule;
+use crate::receipts::{Receipt, ReceiptOutcome};
+use eth_rlp_types::{BlockHeader, ExecutionHeader};
+use ethereum_types::H256;
 
-pub fn create_test_block_header_pectra() -> BlockHeader {
+/// Builds a synthetic, internally consistent segment of Pectra headers on the Hoodi fork schedule.
+///
+/// Each header links to the hash of the previous one, advances the timestamp by 12 seconds and carries
+/// the base fee derived from its parent, so the segment passes every chain check. Tests tamper with
+/// the returned headers before converting them with `BlockHeader::try_from`.
+pub fn create_test_chain(len: usize) -> Vec<ExecutionHeader> {
+    let mut chain = vec![ExecutionHeader {
+        parent_hash: H256::repeat_byte(0x11),
+        ommers_hash: EMPTY_OMMERS_HASH.parse().unwrap(),
+        number: 1_000_000,
+        gas_limit: 36_000_000,
+        gas_used: 12_000_000,
+        timestamp: 1_750_000_000,
+        base_fee_per_gas: Some(1_000_000_000),
+        withdrawals_root: Some(H256::repeat_byte(0x22)),
+        blob_gas_used: Some(0),
+        excess_blob_gas: Some(0),
+        parent_beacon_block_root: Some(H256::repeat_byte(0x33)),
+        requests_hash: Some(H256::repeat_byte(0x44)),
+        ..Default::default()
+    }];
+
+    while chain.len() < len {
+        let parent = chain.last().unwrap();
+        let child = ExecutionHeader {
+            parent_hash: parent.hash(),
+            number: parent.number + 1,
+            timestamp: parent.timestamp + 12,
+            base_fee_per_gas: calc_next_base_fee(parent, &ForkSchedule::HOODI),
+            ..parent.clone()
+        };
+        chain.push(child);
+    }
+    chain
+}
+
+/// Returns the columns of a stored header that the header itself determines, i.e. what parsing it into
+/// its era's struct and converting that back with `into_verifiable` yields.
+///
+/// `totaldifficulty` depends on every earlier block rather than on the header, so it is cleared.
+pub fn header_columns(header: &BlockHeader) -> BlockHeader {
+    BlockHeader {
+        totaldifficulty: None,
+        ..header.clone()
+    }
+}
+
+pub fn create_test_block_header_pectra_sepolia() -> BlockHeader {
     BlockHeader {
         block_hash: "0xbfa14ad39de89b0de89a0d9e78efebae792eae93ee46414ed98ee790ce8ed8b3"
             .to_string(),
@@ -48,6 +100,79 @@ pub fn create_test_block_header_pectra() -> BlockHeader {
     }
 }
 
+pub fn create_test_block_header_pectra_hoodi() -> BlockHeader {
+    BlockHeader {
+        block_hash: "0x5e98e8e4d80928867e03eb2224f66fc8c68f687de3a5550119c365fca7abb118"
+            .to_string(),
+        number: 411443,
+        gas_limit: 36000000,
+        gas_used: 21000,
+        nonce: "0x0000000000000000".to_string(),
+        transaction_root: Some(
+            "0x7cbc552113ed936ee351981d5151a8913cc7cc2ac55d930d6a43ded6e721c21b".to_string(),
+        ),
+        receipts_root: Some(
+            "0x056b23fbba480696b65fe5a59b8f2148a1299103c4f57df839233af2cf4ca2d2".to_string(),
+        ),
+        state_root: Some(
+            "0x7347d30e42da2799eb5b51d8e1a81756323afd47d68e9c7f7fe5c6cfd38572bd".to_string(),
+        ),
+        base_fee_per_gas: Some("0x4227fedf".to_string()),
+        parent_hash: Some(
+            "0x84eba4ac122adba9bbe79b78ccc538ec5fd7b612cd6c2cd6d4ac3a23160f6151".to_string(),
+        ),
+        miner: Some("0x25941dc771bb64514fc8abbce970307fb9d477e9".to_string()),
+        logs_bloom: Some(format!("0x{}", "0".repeat(512))),
+        difficulty: Some("0x0".to_string()),
+        totaldifficulty: None,
+        sha3_uncles: Some(
+            "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347".to_string(),
+        ),
+        timestamp: Some("0x68285874".to_string()),
+        extra_data: Some("0x4e65746865726d696e64".to_string()),
+        mix_hash: Some(
+            "0x5aa29a261f252912f12377c312d68a616af8efef7a9f8c8911b7482bcf4a3adc".to_string(),
+        ),
+        ommers_hash: Some(
+            "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347".to_string(),
+        ),
+        withdrawals_root: Some(
+            "0x9a0aedb6a7b38b44467d87dd8c08b64589fcf729a0f60e9361ecb160f074b08c".to_string(),
+        ),
+        blob_gas_used: Some("0x0".to_string()),
+        excess_blob_gas: Some("0x0".to_string()),
+        parent_beacon_block_root: Some(
+            "0x065c517950023785bf51c075203764504b5fa9b65b8fe3943aa9fb8a86e0391d".to_string(),
+        ),
+        request_hash: Some(
+            "0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".to_string(),
+        ),
+    }
+}
+
+/// The transactions of Hoodi block 411443, whose header is `create_test_block_header_pectra_hoodi`.
+///
+/// The block carries a single EIP-155 legacy transfer, hash
+/// `0x8c22653c4736f3cedf2d36fb4a5565397cfae051b6a5cc53a043078f03aa6ceb`.
+pub fn create_test_block_transactions_pectra_hoodi() -> Vec<Vec<u8>> {
+    vec![hex::decode(
+        "f86b808445f23888825208946dbd21b035b11eb3d921872b1930670904c8280385e8d4a510008083111783a0d22873dda61094957a2a2563fbb5cafde0a16839294fbecf2eb2d498c975d655a05e718d90b3ee82bf2792f6704f98a30ae9c6e90475298a5f84751e840f0e0f9e",
+    )
+    .unwrap()]
+}
+
+/// The receipts of Hoodi block 411443, whose header is `create_test_block_header_pectra_hoodi`: the
+/// successful transfer of `create_test_block_transactions_pectra_hoodi`, which emitted no logs.
+pub fn create_test_block_receipts_pectra_hoodi() -> Vec<Receipt> {
+    vec![Receipt {
+        tx_type: 0,
+        outcome: ReceiptOutcome::Status(true),
+        cumulative_gas_used: 21_000,
+        logs_bloom: Default::default(),
+        logs: vec![],
+    }]
+}
+
 pub fn create_test_block_header_shapella() -> BlockHeader {
     BlockHeader {
         block_hash: "0xb49fa930183f0fa8ca46244e946a635637b90c374d845e9c68dd7fd66cb6e0a1"
@@ -87,11 +212,9 @@ pub fn create_test_block_header_shapella() -> BlockHeader {
         withdrawals_root: Some(
             "0xc32381c919dad80afe8fe0df79460418e350725a63f67c55b27ee168ef464e5d".to_string(),
         ),
-        blob_gas_used: Some("0xa0000".to_string()),
-        excess_blob_gas: Some("0x20000".to_string()),
-        parent_beacon_block_root: Some(
-            "0x2b5b8c2d329148bc5d724cac0a7abc557f93471c24ea027b119ecedb937c0045".to_string(),
-        ),
+        blob_gas_used: None,
+        excess_blob_gas: None,
+        parent_beacon_block_root: None,
         request_hash: None,
     }
 }
@@ -145,7 +268,7 @@ pub fn create_test_block_header_paris() -> BlockHeader {
 
 pub fn create_test_block_header_london() -> BlockHeader {
     BlockHeader {
-        block_hash: "0x9b83c12c69edb74f6c8dd5d052765c1adf940e320bd1291696e6fa07829eee71".to_string(),
+        block_hash: "0xa32d159805750cbe428b799a49b85dcb2300f61d806786f317260e721727d162".to_string(),
         number: 12965001,
         gas_limit: 29999798,
         gas_used: 29985144,
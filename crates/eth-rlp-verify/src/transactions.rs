@@ -0,0 +1,201 @@
+use crate::chain_spec::ChainSpec;
+use crate::eras::{Era, EraHeader, ForkSchedule};
+use crate::error::VerificationError;
+use crate::trie::ordered_trie_root;
+use eth_rlp_types::BlockHeader as VerifiableBlockHeader;
+use ethereum_types::H256;
+
+/// Returns the EIP-2718 type of a raw transaction.
+///
+/// A legacy transaction is a bare RLP list and has type 0; a typed transaction is its type byte followed
+/// by the RLP list of its payload.
+///
+/// # Returns
+///
+/// The transaction type, or `VerificationError::MalformedField` if `raw` is neither a legacy nor a
+/// typed transaction envelope.
+pub fn transaction_type(raw: &[u8]) -> Result<u8, VerificationError> {
+    match raw {
+        [first, ..] if *first >= 0xc0 => Ok(0),
+        [ty, payload, ..] if *ty <= 0x7f && *payload >= 0xc0 => Ok(*ty),
+        _ => Err(VerificationError::malformed(
+            "transactions",
+            "not a legacy or typed transaction envelope",
+        )),
+    }
+}
+
+/// Returns the highest transaction type a block may include.
+///
+/// Access list transactions (type 1) arrived with Berlin, dynamic fee transactions (type 2) with London,
+/// blob transactions (type 3) with Cancun and set-code transactions (type 4) with Prague. Berlin did not
+/// change the header layout, so it is looked up in `forks` for blocks of the Genesis era.
+///
+/// # Arguments
+///
+/// - `era`: The era of the block.
+/// - `block_number`: The number of the block.
+/// - `forks`: The fork schedule of the chain the block belongs to.
+pub fn max_transaction_type(era: Era, block_number: u64, forks: &ForkSchedule) -> u8 {
+    match era {
+        Era::Genesis if forks.berlin_block.is_some_and(|b| block_number >= b) => 1,
+        Era::Genesis => 0,
+        Era::London | Era::Paris | Era::Shapella => 2,
+        Era::Dencun => 3,
+        Era::Pectra => 4,
+    }
+}
+
+/// Computes the transactions root of a block body: the root of the trie that maps the RLP-encoded index
+/// of each transaction to its EIP-2718 encoding.
+///
+/// # Arguments
+///
+/// - `transactions`: The raw transactions of the block, in block order. Legacy transactions are given as
+///   their RLP list and typed transactions as their type byte followed by the payload.
+pub fn transactions_root<T: AsRef<[u8]>>(transactions: &[T]) -> H256 {
+    ordered_trie_root(transactions)
+}
+
+/// Verifies that a list of raw transactions is the body of a block header.
+///
+/// The header is verified against its own hash first, so a successful check ties the transactions to
+/// that hash. Each transaction must be a valid envelope of a type the header's era supports, and the
+/// transactions root computed from them must equal the `transactions_root` of the era header.
+///
+/// # Arguments
+///
+/// - `block_header`: The header of the block.
+/// - `transactions`: The raw transactions of the block, in block order.
+/// - `spec`: The `ChainSpec` of the chain the block belongs to.
+///
+/// # Returns
+///
+/// `Ok(())` if the transactions belong to the header, `VerificationError::RootMismatch` if the roots
+/// differ, or another `VerificationError` if the header or a transaction is invalid.
+pub fn verify_transactions_root<T: AsRef<[u8]>>(
+    block_header: &VerifiableBlockHeader,
+    transactions: &[T],
+    spec: &ChainSpec,
+) -> Result<(), VerificationError> {
+    let block_number = block_header.number as u64;
+    crate::verify_block(
+        block_number,
+        block_header.clone(),
+        &block_header.block_hash,
+        spec,
+    )?;
+    let header = EraHeader::from_header(block_header, &spec.forks)?;
+
+    let max_type = max_transaction_type(header.era(), block_number, &spec.forks);
+    for (index, transaction) in transactions.iter().enumerate() {
+        let ty = transaction_type(transaction.as_ref())?;
+        if ty > max_type {
+            return Err(VerificationError::malformed(
+                "transactions",
+                format!(
+                    "transaction {} has type {}, which the {:?} era does not support",
+                    index,
+                    ty,
+                    header.era()
+                ),
+            ));
+        }
+    }
+
+    let computed = transactions_root(transactions);
+    if computed != header.transactions_root() {
+        return Err(VerificationError::RootMismatch {
+            block_number,
+            field: "transactions_root",
+            expected: header.transactions_root(),
+            computed,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{BERLIN_START, LONDON_START};
+    use crate::hash::keccak256;
+    use crate::test_helpers::{
+        create_test_block_header_pectra_hoodi, create_test_block_transactions_pectra_hoodi,
+        create_test_chain,
+    };
+    use rlp::RlpStream;
+    use std::str::FromStr;
+
+    fn envelope(ty: Option<u8>, nonce: u64) -> Vec<u8> {
+        let mut stream = RlpStream::new_list(2);
+        stream.append(&nonce).append(&"payload");
+        let mut raw: Vec<u8> = ty.into_iter().collect();
+        raw.extend_from_slice(&stream.out());
+        raw
+    }
+
+    #[test]
+    fn test_max_transaction_type_follows_berlin() {
+        let forks = ForkSchedule::MAINNET;
+        assert_eq!(
+            max_transaction_type(Era::Genesis, BERLIN_START - 1, &forks),
+            0
+        );
+        assert_eq!(max_transaction_type(Era::Genesis, BERLIN_START, &forks), 1);
+        assert_eq!(max_transaction_type(Era::London, LONDON_START, &forks), 2);
+        assert_eq!(
+            max_transaction_type(Era::Genesis, 0, &ForkSchedule::SEPOLIA),
+            1
+        );
+    }
+
+    #[test]
+    fn test_verify_transactions_root_hoodi() {
+        let block_header = create_test_block_header_pectra_hoodi();
+        let transactions = create_test_block_transactions_pectra_hoodi();
+        assert_eq!(
+            keccak256(&transactions[0]),
+            H256::from_str("0x8c22653c4736f3cedf2d36fb4a5565397cfae051b6a5cc53a043078f03aa6ceb")
+                .unwrap()
+        );
+        assert_eq!(
+            verify_transactions_root(&block_header, &transactions, &ChainSpec::hoodi()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_verify_transactions_root() {
+        let spec = ChainSpec::hoodi();
+        let transactions = [
+            envelope(None, 0),
+            envelope(Some(2), 1),
+            envelope(Some(4), 2),
+        ];
+        let mut header = create_test_chain(1).remove(0);
+        header.transactions_root = transactions_root(&transactions);
+        let block_header = VerifiableBlockHeader::try_from(&header).unwrap();
+
+        assert_eq!(
+            verify_transactions_root(&block_header, &transactions, &spec),
+            Ok(())
+        );
+        assert!(matches!(
+            verify_transactions_root(&block_header, &transactions[..2], &spec),
+            Err(VerificationError::RootMismatch {
+                field: "transactions_root",
+                ..
+            })
+        ));
+
+        let not_an_envelope = [rlp::encode(&"payload").to_vec()];
+        assert!(matches!(
+            verify_transactions_root(&block_header, &not_an_envelope, &spec),
+            Err(VerificationError::MalformedField {
+                field: "transactions",
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,153 @@
+use crate::hash::keccak256;
+use ethereum_types::H256;
+use rlp::RlpStream;
+
+/// Computes the root of a Merkle Patricia trie holding the given key-value pairs.
+///
+/// The root is built in one pass over the sorted keys without keeping any nodes around, which is all
+/// that is needed to check a root stored in a block header. Nodes whose encoding is shorter than 32
+/// bytes are inlined into their parent, as the Ethereum yellow paper specifies; the root itself is
+/// always hashed.
+///
+/// # Arguments
+///
+/// - `pairs`: The keys and values to insert. Keys must be unique.
+///
+/// # Returns
+///
+/// The Keccak256 hash of the root node, or `EMPTY_ROOT_HASH` if `pairs` is empty.
+pub fn trie_root<K: AsRef<[u8]>, V: AsRef<[u8]>>(pairs: &[(K, V)]) -> H256 {
+    let mut nibbles: Vec<(Vec<u8>, &[u8])> = pairs
+        .iter()
+        .map(|(key, value)| (to_nibbles(key.as_ref()), value.as_ref()))
+        .collect();
+    nibbles.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut stream = RlpStream::new();
+    encode_node(&mut stream, &nibbles, 0);
+    keccak256(&stream.out())
+}
+
+/// Computes the root of a trie that maps the RLP-encoded index of each item to the item itself.
+///
+/// This is how block headers commit to their transactions, receipts and withdrawals.
+pub fn ordered_trie_root<T: AsRef<[u8]>>(items: &[T]) -> H256 {
+    let pairs: Vec<(Vec<u8>, &[u8])> = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| (rlp::encode(&index).to_vec(), item.as_ref()))
+        .collect();
+    trie_root(&pairs)
+}
+
+fn to_nibbles(key: &[u8]) -> Vec<u8> {
+    key.iter()
+        .flat_map(|byte| [byte >> 4, byte & 0x0f])
+        .collect()
+}
+
+/// Encodes a path with the hex-prefix encoding, which also records whether the node is a leaf.
+fn hex_prefix(nibbles: &[u8], leaf: bool) -> Vec<u8> {
+    let flag = if leaf { 0x20 } else { 0x00 };
+    let mut encoded = Vec::with_capacity(nibbles.len() / 2 + 1);
+    let rest = if nibbles.len() % 2 == 1 {
+        encoded.push(flag | 0x10 | nibbles[0]);
+        &nibbles[1..]
+    } else {
+        encoded.push(flag);
+        nibbles
+    };
+    encoded.extend(rest.chunks(2).map(|pair| (pair[0] << 4) | pair[1]));
+    encoded
+}
+
+/// Appends the node holding `pairs`, whose keys all share their first `depth` nibbles, to `stream`.
+fn encode_node(stream: &mut RlpStream, pairs: &[(Vec<u8>, &[u8])], depth: usize) {
+    match pairs {
+        [] => {
+            stream.append_empty_data();
+        }
+        [(key, value)] => {
+            stream.begin_list(2);
+            stream.append(&hex_prefix(&key[depth..], true));
+            stream.append(value);
+        }
+        _ => {
+            // Keys are sorted, so the prefix shared by all of them is the one shared by the first and last.
+            let first = &pairs[0].0[depth..];
+            let last = &pairs[pairs.len() - 1].0[depth..];
+            let shared = first.iter().zip(last).take_while(|(a, b)| a == b).count();
+
+            if shared > 0 {
+                stream.begin_list(2);
+                stream.append(&hex_prefix(&first[..shared], false));
+                append_child(stream, pairs, depth + shared);
+                return;
+            }
+
+            stream.begin_list(17);
+            // A key that ends here sorts first and its value goes into the branch's value slot.
+            let (value, mut rest) = match pairs.split_first() {
+                Some(((key, value), rest)) if key.len() == depth => (Some(*value), rest),
+                _ => (None, pairs),
+            };
+            for nibble in 0..16u8 {
+                let count = rest
+                    .iter()
+                    .take_while(|(key, _)| key[depth] == nibble)
+                    .count();
+                let (children, remaining) = rest.split_at(count);
+                if children.is_empty() {
+                    stream.append_empty_data();
+                } else {
+                    append_child(stream, children, depth + 1);
+                }
+                rest = remaining;
+            }
+            match value {
+                Some(value) => stream.append(&value),
+                None => stream.append_empty_data(),
+            };
+        }
+    }
+}
+
+/// Appends a child node, inlined if its encoding is shorter than a hash and referenced by hash otherwise.
+fn append_child(stream: &mut RlpStream, pairs: &[(Vec<u8>, &[u8])], depth: usize) {
+    let mut child = RlpStream::new();
+    encode_node(&mut child, pairs, depth);
+    let encoded = child.out();
+    if encoded.len() < 32 {
+        stream.append_raw(&encoded, 1);
+    } else {
+        stream.append(&keccak256(&encoded));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::EMPTY_ROOT_HASH;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_trie_root() {
+        let empty: [(&[u8], &[u8]); 0] = [];
+        assert_eq!(trie_root(&empty), H256::from_str(EMPTY_ROOT_HASH).unwrap());
+        assert_eq!(
+            ordered_trie_root::<Vec<u8>>(&[]),
+            H256::from_str(EMPTY_ROOT_HASH).unwrap()
+        );
+
+        let pairs = [
+            ("doe", "reindeer"),
+            ("dog", "puppy"),
+            ("dogglesworth", "cat"),
+        ];
+        assert_eq!(
+            trie_root(&pairs),
+            H256::from_str("0x8aad789dff2f538bca5d8ea56e8abe10f4c7ba3a5dea95fea4cd6e7c3a1168d3")
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use crate::chain_spec::ChainSpec;
+use crate::eras::EraHeader;
+use crate::error::VerificationError;
+use crate::trie::ordered_trie_root;
+use eth_rlp_types::BlockHeader as VerifiableBlockHeader;
+use ethereum_types::{H160, H256};
+use rlp::{Encodable, RlpStream};
+
+/// A withdrawal from the beacon chain to the execution layer (EIP-4895).
+///
+/// # Fields
+///
+/// - `index`: The monotonically increasing index of the withdrawal.
+/// - `validator_index`: The index of the validator whose balance is withdrawn.
+/// - `address`: The execution layer address receiving the withdrawal.
+/// - `amount`: The amount withdrawn, in gwei.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Withdrawal {
+    pub index: u64,
+    pub validator_index: u64,
+    pub address: H160,
+    pub amount: u64,
+}
+
+impl Encodable for Withdrawal {
+    fn rlp_append(&self, stream: &mut RlpStream) {
+        stream.begin_list(4);
+        stream.append(&self.index);
+        stream.append(&self.validator_index);
+        stream.append(&self.address);
+        stream.append(&self.amount);
+    }
+}
+
+/// Computes the withdrawals root of a block: the root of the trie that maps the RLP-encoded index of
+/// each withdrawal to its RLP encoding.
+pub fn withdrawals_root(withdrawals: &[Withdrawal]) -> H256 {
+    let encoded: Vec<_> = withdrawals.iter().map(rlp::encode).collect();
+    ordered_trie_root(&encoded)
+}
+
+/// Verifies that a list of withdrawals belongs to a Shapella or later block header.
+///
+/// The header is verified against its own hash first, so a successful check ties the withdrawals to that
+/// hash. The withdrawals root computed from them must then equal the `withdrawals_root` of the era
+/// header.
+///
+/// # Arguments
+///
+/// - `block_header`: The header of the block.
+/// - `withdrawals`: The withdrawals of the block, in block order.
+/// - `spec`: The `ChainSpec` of the chain the block belongs to.
+///
+/// # Returns
+///
+/// `Ok(())` if the withdrawals belong to the header, `VerificationError::RootMismatch` if the roots
+/// differ, `VerificationError::MalformedField` if the header predates Shapella, or another
+/// `VerificationError` if the header is invalid.
+pub fn verify_withdrawals_root(
+    block_header: &VerifiableBlockHeader,
+    withdrawals: &[Withdrawal],
+    spec: &ChainSpec,
+) -> Result<(), VerificationError> {
+    let block_number = block_header.number as u64;
+    crate::verify_block(
+        block_number,
+        block_header.clone(),
+        &block_header.block_hash,
+        spec,
+    )?;
+    let header = EraHeader::from_header(block_header, &spec.forks)?;
+    let expected = header.withdrawals_root().ok_or_else(|| {
+        VerificationError::malformed(
+            "withdrawals_root",
+            format!("the {:?} era has no withdrawals", header.era()),
+        )
+    })?;
+
+    let computed = withdrawals_root(withdrawals);
+    if computed != expected {
+        return Err(VerificationError::RootMismatch {
+            block_number,
+            field: "withdrawals_root",
+            expected,
+            computed,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::EMPTY_ROOT_HASH;
+    use crate::test_helpers::create_test_chain;
+    use eth_rlp_types::ExecutionHeader;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_verify_withdrawals_root() {
+        let spec = ChainSpec::hoodi();
+        let withdrawals: Vec<_> = (0..3)
+            .map(|i| Withdrawal {
+                index: 1_000 + i,
+                validator_index: 42 + i,
+                address: H160::repeat_byte(0xaa),
+                amount: 32_000_000_000,
+            })
+            .collect();
+        let mut header = create_test_chain(1).remove(0);
+        header.withdrawals_root = Some(withdrawals_root(&withdrawals));
+        let block_header = VerifiableBlockHeader::try_from(&header).unwrap();
+
+        assert_eq!(
+            verify_withdrawals_root(&block_header, &withdrawals, &spec),
+            Ok(())
+        );
+        assert_eq!(
+            verify_withdrawals_root(&block_header, &[], &spec),
+            Err(VerificationError::RootMismatch {
+                block_number: header.number,
+                field: "withdrawals_root",
+                expected: withdrawals_root(&withdrawals),
+                computed: H256::from_str(EMPTY_ROOT_HASH).unwrap(),
+            })
+        );
+
+        let paris = VerifiableBlockHeader::try_from(ExecutionHeader {
+            number: 15_537_394,
+            timestamp: 1_663_224_179,
+            base_fee_per_gas: Some(1_000_000_000),
+            ..Default::default()
+        })
+        .unwrap();
+        assert!(matches!(
+            verify_withdrawals_root(&paris, &[], &ChainSpec::mainnet()),
+            Err(VerificationError::MalformedField {
+                field: "withdrawals_root",
+                ..
+            })
+        ));
+    }
+}